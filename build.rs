@@ -80,7 +80,7 @@ fn get_out_dir_joined(path: String) -> PathBuf {
     Path::new(out_dir).join(path)
 }
 
-fn write_words_data(words_data: &WordsData) {
+fn write_words_data(words_data: &WordsData) -> usize {
     let start = Instant::now();
 
     let lang = words_data.lang.as_str();
@@ -152,6 +152,8 @@ fn write_words_data(words_data: &WordsData) {
         "cargo:warning=-- write_words_data {lang} bytes {:?}",
         start.elapsed()
     );
+
+    words.last().map_or(0, |(length, _)| *length)
 }
 
 const UMLAUTS: [char; 4] = ['ß', 'ä', 'ö', 'ü'];
@@ -220,7 +222,8 @@ fn main() {
     let words_vec = words_vec;
 
     println!("cargo:warning=before write_words_data {:?}", now.elapsed());
-    Parallel::new().each(&words_vec, write_words_data).run();
+    let max_word_lengths: Vec<usize> =
+        Parallel::new().each(&words_vec, write_words_data).run();
     println!("cargo:warning=after write_words_data {:?}", now.elapsed());
 
     let language_count = words_vec.len();
@@ -230,19 +233,40 @@ fn main() {
         format!(
             r###"
 /// Enum representing a language
-#[cfg_attr(feature = "pyo3", pyo3::pyclass(from_py_object, eq))]
-#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+#[cfg_attr(feature = "pyo3", pyo3::pyclass(from_py_object, eq, frozen, hash, module = "hangman_solver"))]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
 pub enum Language {{
     {}
 }}
 
 impl Language {{
+    /// The padded byte count of the words with `length` chars and the words.
+    const fn words_data(self, length: usize) -> (std::num::NonZeroUsize, &'static str) {{
+        match self {{
+            {}
+        }}
+    }}
+
     #[must_use]
     pub const fn read_words(self, length: usize) -> WordSequence {{
-        let (padded_length, words): (std::num::NonZeroUsize, &'static str) = match self {{
+        let (padded_length, words) = self.words_data(length);
+        WordSequence::new(self, length, words, padded_length)
+    }}
+
+    /// Iterate over the words with `length` chars.
+    #[must_use]
+    pub const fn words(self, length: usize) -> StringChunkIter<'static> {{
+        let (padded_length, words) = self.words_data(length);
+        StringChunkIter::new(length, words, padded_length)
+    }}
+
+    /// The length of the longest word of the language.
+    #[allow(clippy::match_same_arms)]
+    #[must_use]
+    pub const fn max_word_length(self) -> usize {{
+        match self {{
             {}
-        }};
-        WordSequence::new(length, words, padded_length)
+        }}
     }}
 
     #[inline]
@@ -282,6 +306,14 @@ impl Language {{
                     data.out_file_name()
                 ))
                 .join("\n,"),
+            words_vec
+                .iter()
+                .zip(&max_word_lengths)
+                .map(|(data, max_word_length)| format!(
+                    "Self::{} => {max_word_length}",
+                    data.enum_name()
+                ))
+                .join(",\n"),
             words_vec
                 .iter()
                 .map(|data| format!("Self::{}", data.enum_name()))
@@ -1,19 +1,33 @@
 from ._solver import (
     solve,
     solve_crossword,
+    solve_many,
+    solve_async,
     read_words_with_length,
+    matches_iter,
+    GameSession,
+    Pattern,
+    WordList,
     UnknownLanguageError,
     HangmanResult,
     Language,
+    LetterStatistic,
 )
 
 __all__ = (
     "solve",
     "solve_crossword",
+    "solve_many",
+    "solve_async",
     "read_words_with_length",
+    "matches_iter",
+    "GameSession",
+    "Pattern",
+    "WordList",
     "UnknownLanguageError",
     "HangmanResult",
     "Language",
+    "LetterStatistic",
 )
 
 from collections.abc import Sequence
@@ -1,14 +1,23 @@
-from collections.abc import Sequence, Mapping
-from typing import Final, Never
+from collections.abc import Iterable, Iterator, Sequence, Mapping
+from asyncio import Future
+from os import PathLike
+from typing import Any, Final, Never
 
 
 __all__ = (
     "solve",
     "solve_crossword",
+    "solve_many",
+    "solve_async",
     "read_words_with_length",
+    "matches_iter",
+    "GameSession",
+    "Pattern",
+    "WordList",
     "UnknownLanguageError",
     "HangmanResult",
     "Language",
+    "LetterStatistic",
 )
 
 class HangmanResult:
@@ -19,11 +28,32 @@ class HangmanResult:
     @property
     def invalid(self, /) -> list[str]: ...
     @property
-    def language(self, /) -> Language: ...
+    def language(self, /) -> Language | None: ...
     @property
     def words(self, /) -> list[str]: ...
     @property
+    def next_offset(self, /) -> int | None: ...
+    @property
     def letter_frequency(self, /) -> list[tuple[str, int]]: ...
+    @property
+    def letter_statistics(self, /) -> list[LetterStatistic]: ...
+
+    def to_dict(self, /) -> dict[str, Any]: ...
+    @staticmethod
+    def from_dict(dict: dict[str, Any], /) -> HangmanResult: ...
+    def __eq__(self, other: object, /) -> bool: ...
+    def __hash__(self, /) -> int: ...
+
+
+class LetterStatistic:
+    @property
+    def letter(self, /) -> str: ...
+    @property
+    def probability(self, /) -> float: ...
+    @property
+    def expected_reveals(self, /) -> float: ...
+    @property
+    def miss_probability(self, /) -> float: ...
 
 
 class Language:
@@ -39,6 +69,8 @@ class Language:
         pass
     def __ne__(self, other: Language, /) -> bool:
         pass
+    def __hash__(self, /) -> int:
+        pass
 
     def __repr__(self, /) -> str:
         pass
@@ -53,6 +85,84 @@ class Language:
     En: Final[Language]
 
 
+class GameSession:
+    def __init__(self, language: Language, pattern: str, /) -> None: ...
+
+    @property
+    def language(self, /) -> Language: ...
+    @property
+    def pattern(self, /) -> str: ...
+    @property
+    def invalid_letters(self, /) -> list[str]: ...
+    @property
+    def is_solved(self, /) -> bool: ...
+    @property
+    def guesses(self, /) -> list[tuple[str, list[int]]]: ...
+
+    def guess(self, letter: str, positions: Sequence[int] = (), /) -> None:
+        pass
+
+    def undo(self, /) -> bool:
+        pass
+
+    def result(self, max_words_to_collect: int | None = None, /) -> HangmanResult:
+        pass
+
+    def suggestion(self, /) -> str | None:
+        pass
+
+
+class Pattern:
+    def __init__(
+        self,
+        pattern: str,
+        invalid_letters: str = "",
+        crossword: bool = False,
+        wildcards: str = "",
+    ) -> None: ...
+
+    @property
+    def pattern(self, /) -> str: ...
+    @property
+    def invalid_letters(self, /) -> list[str]: ...
+    @property
+    def known_letters_count(self, /) -> int: ...
+    @property
+    def crossword(self, /) -> bool: ...
+
+    def matches(self, word: str, /) -> bool:
+        pass
+
+    def solve(
+        self,
+        language: Language | WordList,
+        max_words_to_collect: int,
+        offset: int = 0,
+        seed: int | None = None,
+    ) -> HangmanResult:
+        pass
+
+    def matches_iter(self, language: Language | WordList, /) -> Iterator[str]:
+        pass
+
+
+class WordList:
+    def __init__(self, words: Iterable[str], /) -> None: ...
+
+    @staticmethod
+    def from_file(path: str | PathLike[str], /) -> WordList:
+        pass
+
+    def read_words(self, word_length: int, /) -> Sequence[str]:
+        pass
+
+    @property
+    def max_word_length(self, /) -> int: ...
+
+    def __len__(self, /) -> int:
+        pass
+
+
 class UnknownLanguageError(ValueError):
     pass
 
@@ -60,21 +170,55 @@ class UnknownLanguageError(ValueError):
 def read_words_with_length(language: Language, word_length: int, /) -> Sequence[str]:
     pass
 
+def matches_iter(
+    pattern_string: str,
+    invalid_letters: Sequence[str] | str,
+    language: Language | WordList,
+    crossword: bool = False,
+) -> Iterator[str]:
+    pass
+
 def solve(
     pattern_string: str,
     invalid_letters: Sequence[str] | str,
-    language: Language,
-    max_words_to_collect: int
+    language: Language | WordList,
+    max_words_to_collect: int,
+    offset: int = 0,
+    seed: int | None = None,
 ) -> HangmanResult:
     pass
 
 def solve_crossword(
     pattern_string: str,
     invalid_letters: Sequence[str] | str,
-    language: Language,
-    max_words_to_collect: int
+    language: Language | WordList,
+    max_words_to_collect: int,
+    offset: int = 0,
+    seed: int | None = None,
 ) -> HangmanResult:
     pass
 
+def solve_many(
+    patterns: Iterable[Pattern | str | tuple[str, Sequence[str] | str]],
+    language: Language | WordList,
+    max_words_to_collect: int,
+    crossword: bool = False,
+    offset: int = 0,
+    seed: int | None = None,
+) -> list[HangmanResult]:
+    pass
+
+def solve_async(
+    pattern_string: str,
+    invalid_letters: Sequence[str] | str,
+    language: Language | WordList,
+    max_words_to_collect: int,
+    offset: int = 0,
+    seed: int | None = None,
+    *,
+    crossword: bool = False,
+) -> Future[HangmanResult]:
+    pass
+
 
-del Sequence, Mapping, Final, Never
+del Iterable, Iterator, Sequence, Mapping, Future, PathLike, Any, Final, Never
@@ -0,0 +1,30 @@
+// SPDX-License-Identifier: EUPL-1.2
+#![warn(
+    clippy::missing_const_for_fn,
+    clippy::nursery,
+    clippy::pedantic,
+    clippy::todo
+)]
+#![deny(clippy::indexing_slicing, clippy::panic, clippy::unwrap_used)]
+#![deny(unsafe_code)]
+
+use std::env;
+use std::process::exit;
+
+const DEFAULT_ADDRESS: &str = "127.0.0.1:8080";
+
+fn main() {
+    let mut args = env::args().skip(1);
+    let address = args.next().unwrap_or_else(|| DEFAULT_ADDRESS.to_owned());
+
+    if args.next().is_some() {
+        eprintln!("Usage: hangman_solver_server [ADDRESS]");
+        exit(2);
+    }
+
+    eprintln!("Listening on http://{address}");
+    if let Err(error) = hangman_solver_lib::server::serve(&address) {
+        eprintln!("{error}");
+        exit(1);
+    }
+}
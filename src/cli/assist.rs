@@ -0,0 +1,190 @@
+// SPDX-License-Identifier: EUPL-1.2
+use hangman_solver_lib::{Language, Session, SessionError};
+
+use crate::cli::{Args, get_terminal_width, prompt};
+
+const HELP: &str = "\
+Commands:
+  guess <letter>: miss      The letter is not in the word
+  guess <letter> at <1,..>  The letter is at the positions, starting at 1
+  undo                      Revert the last guess
+  show                      Show the matching words and letters
+  suggest                   Suggest the next letter
+  new <length|pattern>      Start with a new word
+  help                      Show this help
+  quit                      Exit";
+
+/// A parsed line of the assistant.
+#[derive(Debug, PartialEq, Eq)]
+enum Input {
+    Miss(char),
+    Hit(char, Vec<usize>),
+    Undo,
+    Show,
+    Suggest,
+    New(String),
+    Help,
+    Quit,
+}
+
+/// Parse the positions, starting at 1, to zero based positions.
+fn parse_positions(positions: &str) -> Option<Vec<usize>> {
+    positions
+        .split(|ch: char| ch == ',' || ch.is_whitespace())
+        .filter(|position| !position.is_empty())
+        .map(|position| position.parse::<usize>().ok()?.checked_sub(1))
+        .collect()
+}
+
+fn parse_guess(guess: &str) -> Result<Input, &'static str> {
+    let mut chars = guess.chars();
+    let letter = chars.next().ok_or("guess needs a letter")?;
+    let rest = chars.as_str().trim_start_matches(':').trim();
+
+    if rest == "miss" {
+        return Ok(Input::Miss(letter));
+    }
+    let positions = rest.strip_prefix("at").unwrap_or(rest);
+    match parse_positions(positions) {
+        Some(positions) if !positions.is_empty() => {
+            Ok(Input::Hit(letter, positions))
+        }
+        _ => Err("guess needs \"miss\" or the positions of the letter"),
+    }
+}
+
+fn parse_input(line: &str) -> Result<Input, &'static str> {
+    let (command, rest) = line.split_once(' ').unwrap_or((line, ""));
+    let rest = rest.trim();
+
+    match command {
+        "guess" | "g" => parse_guess(rest),
+        "undo" | "u" => Ok(Input::Undo),
+        "show" | "s" => Ok(Input::Show),
+        "suggest" => Ok(Input::Suggest),
+        "new" | "n" if !rest.is_empty() => Ok(Input::New(rest.to_owned())),
+        "help" | "h" | "?" => Ok(Input::Help),
+        "quit" | "exit" | "q" => Ok(Input::Quit),
+        _ => Err("unknown command, enter help to see all commands"),
+    }
+}
+
+/// Create a session from a length like `5` or a pattern like `t__t`.
+fn new_session(
+    language: Language,
+    word: &str,
+) -> Result<Session, SessionError> {
+    word.parse::<usize>().map_or_else(
+        |_| Ok(Session::with_pattern(language, word)),
+        |length| Session::new(language, length),
+    )
+}
+
+fn print_state(session: &Session) {
+    let invalid: String = session.invalid_letters().iter().collect();
+    println!("{} (invalid: {invalid})", session.pattern());
+
+    if session.is_solved() {
+        println!("The word is solved.");
+    } else if let Some(letter) = session.suggestion() {
+        println!("Suggested letter: {letter}");
+    } else {
+        println!("No word matches.");
+    }
+}
+
+/// Assist with a game hosted by someone else, keeping the state between lines.
+pub fn run(args: &Args, language: Language) -> i32 {
+    let word = match args.operands.as_slice() {
+        [word] => Some(word.clone()),
+        [] => prompt("Enter the length or the pattern of the word: "),
+        _ => {
+            eprintln!("Too many arguments");
+            return 1;
+        }
+    };
+    let Some(word) = word.filter(|word| !word.trim().is_empty()) else {
+        eprintln!("Invalid word length");
+        return 1;
+    };
+
+    let mut session = match new_session(language, word.trim()) {
+        Ok(session) => session,
+        Err(error) => {
+            eprintln!("{error}");
+            return 1;
+        }
+    };
+    print_state(&session);
+
+    while let Some(line) = prompt("> ") {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        let result: Result<(), SessionError> = match parse_input(line) {
+            Err(error) => {
+                println!("{error}");
+                continue;
+            }
+            Ok(Input::Miss(letter)) => session.miss(letter),
+            Ok(Input::Hit(letter, positions)) => {
+                session.hit(letter, &positions)
+            }
+            Ok(Input::Undo) => {
+                if !session.undo() {
+                    println!("Nothing to undo.");
+                }
+                Ok(())
+            }
+            Ok(Input::Show) => {
+                let width = get_terminal_width();
+                let result = session.solve(args.max_words.or_else(|| {
+                    Some(width / (session.pattern().chars().count() + 1) + 1)
+                }));
+                println!("{result:─^width$}");
+                continue;
+            }
+            Ok(Input::Suggest) => Ok(()),
+            Ok(Input::New(word)) => new_session(language, &word)
+                .map(|new_session| session = new_session),
+            Ok(Input::Help) => {
+                println!("{HELP}");
+                continue;
+            }
+            Ok(Input::Quit) => break,
+        };
+
+        if let Err(error) = result {
+            println!("{error}");
+        }
+        print_state(&session);
+    }
+
+    0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Input, parse_input};
+
+    #[test]
+    fn test_parse_input() {
+        assert_eq!(parse_input("guess e: miss"), Ok(Input::Miss('e')));
+        assert_eq!(parse_input("guess e miss"), Ok(Input::Miss('e')));
+        assert_eq!(
+            parse_input("guess n at 2,5"),
+            Ok(Input::Hit('n', vec![1, 4]))
+        );
+        assert_eq!(parse_input("g n: 2 5"), Ok(Input::Hit('n', vec![1, 4])));
+        assert_eq!(parse_input("undo"), Ok(Input::Undo));
+        assert_eq!(parse_input("new t__t"), Ok(Input::New("t__t".into())));
+
+        assert!(parse_input("guess n at 0").is_err());
+        assert!(parse_input("guess n").is_err());
+        assert!(parse_input("guess").is_err());
+        assert!(parse_input("new").is_err());
+        assert!(parse_input("dance").is_err());
+    }
+}
@@ -0,0 +1,284 @@
+// SPDX-License-Identifier: EUPL-1.2
+//! Solve the patterns of files in parallel.
+//!
+//! Every line is a pattern, optionally followed by the invalid letters.
+//! Options in front of the pattern override the command line for the line:
+//! `lang=en`, `mode=crossword` or `mode=normal` and `max=10`.
+//! Empty lines are ignored.
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Read, Write};
+use std::time::{Duration, Instant};
+
+use hangman_solver_lib::parallel::{par_map, thread_count};
+use hangman_solver_lib::{
+    HangmanResult, Language, ResultFormat, WordSelection, solve_infallible,
+};
+
+use crate::cli::{Args, OutputFormat, get_terminal_width};
+
+#[derive(Debug, PartialEq, Eq)]
+struct Job {
+    /// The file name and line number, for errors.
+    location: String,
+    pattern: String,
+    invalid: String,
+    language: Language,
+    crossword: bool,
+    max_words: Option<usize>,
+}
+
+/// Parse a line, returns `Ok(None)` for lines without a pattern.
+fn parse_line(
+    line: &str,
+    location: String,
+    args: &Args,
+    language: Language,
+) -> Result<Option<Job>, String> {
+    let line = line.trim();
+    if line.is_empty() {
+        return Ok(None);
+    }
+
+    let mut job = Job {
+        location,
+        pattern: String::new(),
+        invalid: String::new(),
+        language,
+        crossword: args.crossword,
+        max_words: args.max_words,
+    };
+    let mut words = line.split_whitespace().peekable();
+
+    while let Some((key, value)) =
+        words.peek().and_then(|word| word.split_once('='))
+    {
+        match key {
+            "lang" | "language" => {
+                job.language = Language::from_string(value)
+                    .ok_or_else(|| format!("invalid language: {value}"))?;
+            }
+            "mode" => {
+                job.crossword = match value {
+                    "crossword" => true,
+                    "normal" => false,
+                    _ => return Err(format!("invalid mode: {value}")),
+                };
+            }
+            "max" | "max_words" => {
+                job.max_words = Some(
+                    value
+                        .parse()
+                        .map_err(|_| format!("invalid max words: {value}"))?,
+                );
+            }
+            _ => return Err(format!("unknown option: {key}")),
+        }
+        words.next();
+    }
+
+    words
+        .next()
+        .ok_or("missing pattern")?
+        .clone_into(&mut job.pattern);
+    job.invalid = words.collect();
+
+    Ok(Some(job))
+}
+
+/// Read the input files, or stdin if there are none.
+fn read_inputs(files: &[String]) -> io::Result<Vec<(String, String)>> {
+    if files.is_empty() {
+        let mut input = String::new();
+        io::stdin().read_to_string(&mut input)?;
+        return Ok(vec![("<stdin>".to_owned(), input)]);
+    }
+
+    files
+        .iter()
+        .map(|file| Ok((file.clone(), fs::read_to_string(file)?)))
+        .collect()
+}
+
+fn write_results(
+    out: &mut impl Write,
+    args: &Args,
+    results: &[(HangmanResult, Duration)],
+) -> io::Result<()> {
+    let width = get_terminal_width();
+
+    match args.format {
+        OutputFormat::Text => {
+            if args.template == ResultFormat::Csv {
+                writeln!(out, "{}", ResultFormat::CSV_HEADER)?;
+            }
+            for (result, _) in results {
+                writeln!(
+                    out,
+                    "{:─^width$}",
+                    result.display_with(&args.template)
+                )?;
+            }
+        }
+        #[cfg(feature = "serde")]
+        OutputFormat::Json => {
+            let results: Vec<&HangmanResult> =
+                results.iter().map(|(result, _)| result).collect();
+            serde_json::to_writer_pretty(&mut *out, &results)?;
+            writeln!(out)?;
+        }
+        #[cfg(feature = "serde")]
+        OutputFormat::JsonLines => {
+            for (result, _) in results {
+                serde_json::to_writer(&mut *out, result)?;
+                writeln!(out)?;
+            }
+        }
+    }
+
+    out.flush()
+}
+
+/// Solve the patterns in the files, or stdin if there are none.
+pub fn run(args: &Args, language: Language) -> i32 {
+    let inputs = match read_inputs(&args.operands) {
+        Ok(inputs) => inputs,
+        Err(error) => {
+            eprintln!("{error}");
+            return 1;
+        }
+    };
+
+    let mut errors = 0usize;
+    let jobs: Vec<Job> = inputs
+        .iter()
+        .flat_map(|(file, input)| {
+            input.lines().enumerate().map(move |(index, line)| {
+                (format!("{file}:{}", index + 1), line)
+            })
+        })
+        .filter_map(|(location, line)| {
+            parse_line(line, location.clone(), args, language).unwrap_or_else(
+                |error| {
+                    eprintln!("{location}: {error}");
+                    errors += 1;
+                    None
+                },
+            )
+        })
+        .collect();
+
+    let start = Instant::now();
+    let results: Vec<(HangmanResult, Duration)> = par_map(&jobs, |job| {
+        let start = Instant::now();
+        let max_words = args.words_to_collect(job.max_words, || {
+            get_terminal_width() / (job.pattern.chars().count() + 1) + 1
+        });
+        let result = solve_infallible(
+            job.pattern.as_str(),
+            job.invalid.as_str(),
+            !job.crossword,
+            job.language,
+            max_words,
+            WordSelection::default(),
+        );
+        (result, start.elapsed())
+    });
+    let elapsed = start.elapsed();
+
+    let written = match &args.output {
+        Some(output) => File::create(output).and_then(|file| {
+            write_results(&mut BufWriter::new(file), args, &results)
+        }),
+        None => write_results(&mut io::stdout().lock(), args, &results),
+    };
+    if let Err(error) = written {
+        eprintln!("{error}");
+        return 1;
+    }
+
+    print_summary(&jobs, &results, elapsed, errors);
+    i32::from(errors > 0)
+}
+
+fn print_summary(
+    jobs: &[Job],
+    results: &[(HangmanResult, Duration)],
+    elapsed: Duration,
+    errors: usize,
+) {
+    let count = results.len();
+    let solving_time: Duration = results.iter().map(|(_, time)| *time).sum();
+    let no_match = results
+        .iter()
+        .filter(|(result, _)| result.matching_words_count == 0)
+        .count();
+    let single_match = results
+        .iter()
+        .filter(|(result, _)| result.matching_words_count == 1)
+        .count();
+    let total_words: u64 = results
+        .iter()
+        .map(|(result, _)| u64::from(result.matching_words_count))
+        .sum();
+
+    eprintln!(
+        "Solved {count} patterns in {elapsed:.2?} with {} threads ({:.2?} per pattern)",
+        thread_count(count),
+        solving_time / u32::try_from(count.max(1)).unwrap_or(u32::MAX),
+    );
+    eprintln!(
+        "Matches: {total_words} words, {single_match} patterns with one word, {no_match} without words"
+    );
+    if let Some((job, (_, time))) =
+        jobs.iter().zip(results).max_by_key(|(_, (_, time))| *time)
+    {
+        eprintln!("Slowest: {} ({time:.2?})", job.location);
+    }
+    if errors > 0 {
+        eprintln!("Skipped {errors} invalid lines");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hangman_solver_lib::Language;
+
+    use super::parse_line;
+    use crate::cli::Args;
+
+    #[test]
+    fn test_parse_line() {
+        let args = Args::default();
+        let parse = |line: &str| {
+            parse_line(line, "test:1".to_owned(), &args, Language::De)
+        };
+
+        assert_eq!(parse(""), Ok(None));
+        assert_eq!(parse("  "), Ok(None));
+
+        let job = parse("te_t xy z").expect("valid").expect("a pattern");
+        assert_eq!(job.pattern, "te_t");
+        assert_eq!(job.invalid, "xyz");
+        assert_eq!(job.language, Language::De);
+        assert!(!job.crossword);
+        assert_eq!(job.max_words, None);
+
+        let job = parse("####### e").expect("valid").expect("a pattern");
+        assert_eq!(job.pattern, "#######");
+
+        let job = parse("lang=en mode=crossword max=3 te_t")
+            .expect("valid")
+            .expect("a pattern");
+        assert_eq!(job.pattern, "te_t");
+        assert_eq!(job.invalid, "");
+        assert_eq!(job.language, Language::En);
+        assert!(job.crossword);
+        assert_eq!(job.max_words, Some(3));
+
+        assert!(parse("lang=xx te_t").is_err());
+        assert!(parse("mode=x te_t").is_err());
+        assert!(parse("max=x te_t").is_err());
+        assert!(parse("speed=3 te_t").is_err());
+        assert!(parse("lang=en").is_err());
+    }
+}
@@ -0,0 +1,166 @@
+// SPDX-License-Identifier: EUPL-1.2
+use hangman_solver_lib::{Language, Pattern, WordSelection};
+use unwrap_infallible::UnwrapInfallible;
+
+use crate::cli::{Args, prompt};
+
+/// Let the computer guess a word thought of by the user.
+pub fn run(args: &Args, language: Language) -> i32 {
+    let length = match args.operands.as_slice() {
+        [length] => length.clone(),
+        [] => {
+            prompt("How many letters does your word have? ").unwrap_or_default()
+        }
+        _ => {
+            eprintln!("Too many arguments");
+            return 1;
+        }
+    };
+    let length = match parse_length(&length, language) {
+        Ok(length) => length,
+        Err(message) => {
+            eprintln!("{message}");
+            return 1;
+        }
+    };
+
+    let mut pattern: Vec<char> = vec!['_'; length];
+    let mut invalid: Vec<char> = Vec::new();
+
+    loop {
+        let result = Pattern::new(&pattern, &invalid, !args.crossword)
+            .unwrap_infallible()
+            .solve(language, Some(1), WordSelection::default());
+
+        if result.matching_words_count == 1
+            && let Some(word) = result.possible_words.first()
+        {
+            println!("Your word is {word}.");
+            return 0;
+        }
+        let Some(&(guess, _)) = result.letter_frequency.first() else {
+            println!("I don't know your word.");
+            return 1;
+        };
+
+        println!("{}", String::from_iter(&pattern));
+        let Some(line) = prompt(&format!(
+            "Where is {guess} in your word? (positions, empty if nowhere) "
+        )) else {
+            println!();
+            return 0;
+        };
+
+        let positions = match parse_positions(&line, &pattern) {
+            Ok(positions) => positions,
+            Err(message) => {
+                println!("{message}");
+                continue;
+            }
+        };
+
+        if positions.is_empty() {
+            invalid.push(guess);
+        }
+        for index in positions {
+            if let Some(ch) = pattern.get_mut(index) {
+                *ch = guess;
+            }
+        }
+    }
+}
+
+/// Parse the length of the word, at most the length of the longest word.
+fn parse_length(line: &str, language: Language) -> Result<usize, String> {
+    let length = line
+        .trim()
+        .parse::<usize>()
+        .ok()
+        .filter(|length| *length > 0)
+        .ok_or_else(|| "Invalid word length".to_owned())?;
+    if length > language.max_word_length() {
+        return Err(format!("There are no words with {length} letters."));
+    }
+
+    Ok(length)
+}
+
+/// Parse the 1-based positions of a line to the indices in the pattern.
+///
+/// Positions that are already revealed or entered twice are rejected,
+/// so every answer reveals something new.
+fn parse_positions(line: &str, pattern: &[char]) -> Result<Vec<usize>, String> {
+    let mut indices: Vec<usize> = Vec::new();
+
+    for position in line
+        .split(|ch: char| ch == ',' || ch.is_whitespace())
+        .filter(|position| !position.is_empty())
+    {
+        let Some(index) = position
+            .parse::<usize>()
+            .ok()
+            .filter(|position| (1..=pattern.len()).contains(position))
+            .map(|position| position - 1)
+        else {
+            return Err(format!(
+                "Please enter positions from 1 to {}.",
+                pattern.len()
+            ));
+        };
+        if indices.contains(&index) {
+            return Err(format!("Position {position} was entered twice."));
+        }
+        if pattern.get(index) != Some(&'_') {
+            return Err(format!("Position {position} is already revealed."));
+        }
+        indices.push(index);
+    }
+
+    Ok(indices)
+}
+
+#[cfg(test)]
+mod tests {
+    use hangman_solver_lib::Language;
+
+    use super::{parse_length, parse_positions};
+
+    #[test]
+    fn test_parse_length() {
+        let longest = Language::En.max_word_length();
+
+        assert_eq!(parse_length("4", Language::En), Ok(4));
+        assert_eq!(parse_length(" 4\n", Language::En), Ok(4));
+        assert_eq!(
+            parse_length(&longest.to_string(), Language::En),
+            Ok(longest)
+        );
+        assert!(
+            parse_length(&(longest + 1).to_string(), Language::En).is_err()
+        );
+        assert!(parse_length("100000000000", Language::En).is_err());
+        assert!(parse_length("0", Language::En).is_err());
+        assert!(parse_length("", Language::En).is_err());
+        assert!(parse_length("x", Language::En).is_err());
+    }
+
+    #[test]
+    fn test_parse_positions() {
+        let pattern = ['_', 'e', '_', '_'];
+
+        assert_eq!(parse_positions("", &pattern), Ok(vec![]));
+        assert_eq!(parse_positions("1, 4", &pattern), Ok(vec![0, 3]));
+        assert_eq!(parse_positions("3 1", &pattern), Ok(vec![2, 0]));
+        assert_eq!(
+            parse_positions("2", &pattern),
+            Err("Position 2 is already revealed.".to_owned())
+        );
+        assert_eq!(
+            parse_positions("1,1", &pattern),
+            Err("Position 1 was entered twice.".to_owned())
+        );
+        assert!(parse_positions("0", &pattern).is_err());
+        assert!(parse_positions("5", &pattern).is_err());
+        assert!(parse_positions("x", &pattern).is_err());
+    }
+}
@@ -0,0 +1,363 @@
+// SPDX-License-Identifier: EUPL-1.2
+mod assist;
+mod batch;
+mod host;
+mod play;
+#[cfg(feature = "serde")]
+mod rpc;
+mod simulate;
+mod solve;
+#[cfg(feature = "tui")]
+mod tui;
+mod words;
+
+use std::io::{self, BufRead, Write};
+
+use hangman_solver_lib::{Language, ResultFormat};
+use itertools::Itertools;
+#[cfg(feature = "terminal_size")]
+use terminal_size::{Width, terminal_size};
+
+const USAGE: &str = "\
+Usage: hangman_solver [COMMAND] [OPTIONS] [LANGUAGE] [ARGS]...
+
+Commands:
+  solve     Solve the patterns read from stdin (default)
+  play      Guess a word picked by the computer
+  host      Let the computer guess a word you think of
+  assist    Get suggestions while guessing a word, step by step
+  words     Inspect the word lists, see words LANGUAGE help
+  simulate  Let the solver play against the given words
+  batch     Solve the patterns in the files in parallel
+  tui       Show the matching words while typing, needs the tui feature
+  rpc       Answer JSON-RPC 2.0 requests from stdin, the language is optional,
+            needs the serde feature
+
+Options:
+  -l, --language <LANGUAGE>  The language of the words
+  -c, --crossword            Letters in the pattern can appear in other positions
+  -n, --max-words <N>        The maximum count of words to print
+  -f, --format <FORMAT>      The output format [possible values: text, json, jsonl]
+                             json and jsonl need the serde feature
+  -o, --output <FILE>        The file to write the output of batch to
+  -t, --template <FORMAT>    The layout of the text output [possible values:
+                             default, full, count, letters, letters:K, csv,
+                             or a template like \"{count} words: {words}\"]
+  -h, --help                 Print this help
+
+Input lines of solve are the pattern followed by the invalid letters,
+separated by a space, e.g. \"_a__e_ xyz\".
+The fields of templates are input, invalid, count, words, letters, language
+and next_offset, {{ and }} are literal braces.";
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Command {
+    Solve,
+    Play,
+    Host,
+    Assist,
+    Words,
+    Simulate,
+    Batch,
+    #[cfg(feature = "serde")]
+    Rpc,
+    #[cfg(feature = "tui")]
+    Tui,
+    Help,
+}
+
+impl Command {
+    fn from_string(string: &str) -> Option<Self> {
+        match string {
+            "solve" => Some(Self::Solve),
+            "play" => Some(Self::Play),
+            "host" => Some(Self::Host),
+            "assist" => Some(Self::Assist),
+            "words" => Some(Self::Words),
+            "simulate" => Some(Self::Simulate),
+            "batch" => Some(Self::Batch),
+            #[cfg(feature = "serde")]
+            "rpc" => Some(Self::Rpc),
+            #[cfg(feature = "tui")]
+            "tui" => Some(Self::Tui),
+            "help" => Some(Self::Help),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Default)]
+pub enum OutputFormat {
+    /// The human readable output, shortened to the terminal width.
+    #[default]
+    Text,
+    /// A JSON array with an object for every input line.
+    #[cfg(feature = "serde")]
+    Json,
+    /// A JSON object in a line for every input line.
+    #[cfg(feature = "serde")]
+    JsonLines,
+}
+
+impl OutputFormat {
+    fn from_string(string: &str) -> Option<Self> {
+        match string.to_lowercase().as_str() {
+            "text" => Some(Self::Text),
+            #[cfg(feature = "serde")]
+            "json" => Some(Self::Json),
+            #[cfg(feature = "serde")]
+            "jsonl" | "json-lines" => Some(Self::JsonLines),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Args {
+    pub command: Option<Command>,
+    pub language: Option<String>,
+    pub crossword: bool,
+    pub max_words: Option<usize>,
+    pub format: OutputFormat,
+    pub output: Option<String>,
+    /// The layout of results in the text output
+    pub template: ResultFormat,
+    /// The positional arguments
+    pub operands: Vec<String>,
+}
+
+fn parse_value<T>(
+    name: &str,
+    value: Option<String>,
+    parse: impl FnOnce(&str) -> Option<T>,
+) -> Result<T, String> {
+    let value = value.ok_or_else(|| format!("{name} needs a value"))?;
+    parse(&value).ok_or_else(|| format!("invalid value for {name}: {value}"))
+}
+
+impl Args {
+    pub fn parse(
+        args: impl IntoIterator<Item = String>,
+    ) -> Result<Self, String> {
+        let mut parsed = Self::default();
+        let mut args = args.into_iter();
+        let mut only_operands = false;
+
+        while let Some(arg) = args.next() {
+            if only_operands || arg == "-" || !arg.starts_with('-') {
+                if parsed.command.is_none()
+                    && parsed.operands.is_empty()
+                    && let Some(command) = Command::from_string(&arg)
+                {
+                    parsed.command = Some(command);
+                } else {
+                    parsed.operands.push(arg);
+                }
+                continue;
+            }
+            if arg == "--" {
+                only_operands = true;
+                continue;
+            }
+
+            let (name, inline_value) = match arg.split_once('=') {
+                Some((name, value)) if name.starts_with("--") => {
+                    (name.to_owned(), Some(value.to_owned()))
+                }
+                _ => (arg, None),
+            };
+            let mut value = || inline_value.clone().or_else(|| args.next());
+
+            match name.as_str() {
+                "-h" | "--help" => parsed.command = Some(Command::Help),
+                "-c" | "--crossword" => parsed.crossword = true,
+                "-l" | "--language" => {
+                    parsed.language = Some(parse_value(&name, value(), |v| {
+                        Some(v.to_owned())
+                    })?);
+                }
+                "-n" | "--max-words" => {
+                    parsed.max_words =
+                        Some(parse_value(&name, value(), |v| v.parse().ok())?);
+                }
+                "-o" | "--output" => {
+                    parsed.output = Some(parse_value(&name, value(), |v| {
+                        Some(v.to_owned())
+                    })?);
+                }
+                "-f" | "--format" => {
+                    parsed.format =
+                        parse_value(&name, value(), OutputFormat::from_string)?;
+                }
+                "-t" | "--template" => {
+                    let template = parse_value(&name, value(), |v| {
+                        Some(ResultFormat::from_string(v))
+                    })?;
+                    parsed.template = template
+                        .map_err(|error| format!("invalid {name}: {error}"))?;
+                }
+                _ => return Err(format!("unknown option: {name}")),
+            }
+        }
+
+        Ok(parsed)
+    }
+
+    /// The count of words to collect for a result.
+    ///
+    /// Without a limit the shortened text output only collects the words
+    /// that fit into the terminal, the other layouts collect what they show.
+    fn words_to_collect(
+        &self,
+        max_words: Option<usize>,
+        words_fitting: impl FnOnce() -> usize,
+    ) -> Option<usize> {
+        if max_words.is_some() {
+            return max_words;
+        }
+        match (self.format, &self.template) {
+            (OutputFormat::Text, ResultFormat::Default) => {
+                Some(words_fitting())
+            }
+            (
+                OutputFormat::Text,
+                ResultFormat::Count | ResultFormat::Letters(_),
+            ) => Some(0),
+            _ => None,
+        }
+    }
+
+    /// The language, from the option or the first operand.
+    fn take_language(&mut self) -> Option<String> {
+        self.language.take().or_else(|| {
+            (!self.operands.is_empty()).then(|| self.operands.remove(0))
+        })
+    }
+}
+
+pub fn valid_languages() -> String {
+    Language::all().iter().map(Language::name).join(", ")
+}
+
+pub fn get_terminal_width() -> usize {
+    #[cfg(feature = "terminal_size")]
+    if let Some((Width(w), _)) = terminal_size() {
+        return w.into();
+    }
+
+    80
+}
+
+/// Print a prompt and read a line from stdin, without the line break.
+///
+/// Returns `None` at the end of the input.
+pub fn prompt(prompt: &str) -> Option<String> {
+    print!("{prompt}");
+    let _ = io::stdout().flush();
+
+    let mut line = String::new();
+    let read = io::stdin().lock().read_line(&mut line);
+    match read {
+        Ok(0) | Err(_) => None,
+        Ok(_) => Some(line.trim_end_matches(['\n', '\r']).to_owned()),
+    }
+}
+
+/// Run the command line interface, returns the exit code.
+pub fn run(mut args: Args) -> i32 {
+    let command = args.command.unwrap_or(Command::Solve);
+
+    match command {
+        Command::Help => {
+            println!("{USAGE}");
+            return 0;
+        }
+        #[cfg(feature = "serde")]
+        Command::Rpc => return rpc::run(&mut args),
+        _ => {}
+    }
+
+    let Some(language) = args.take_language() else {
+        eprintln!("Please set a language as argument.");
+        eprintln!("Valid languages: {}", valid_languages());
+        return 2;
+    };
+    let Some(language) = Language::from_string(&language) else {
+        eprintln!("Invalid language");
+        return 1;
+    };
+
+    match command {
+        Command::Solve => solve::run(&args, language),
+        Command::Play => play::run(&args, language),
+        Command::Host => host::run(&args, language),
+        Command::Assist => assist::run(&args, language),
+        Command::Words => words::run(&args, language),
+        Command::Simulate => simulate::run(&args, language),
+        Command::Batch => batch::run(&args, language),
+        #[cfg(feature = "tui")]
+        Command::Tui => tui::run(&args, language),
+        #[cfg(feature = "serde")]
+        Command::Rpc => unreachable!("rpc is handled above"),
+        Command::Help => unreachable!("help is handled above"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hangman_solver_lib::ResultFormat;
+
+    use super::{Args, Command, OutputFormat};
+
+    fn parse(args: &[&str]) -> Result<Args, String> {
+        Args::parse(args.iter().map(|arg| String::from(*arg)))
+    }
+
+    #[test]
+    fn test_parse_args() {
+        let args = parse(&["de"]).expect("valid arguments");
+        assert_eq!(args.command, None);
+        assert_eq!(args.operands, ["de"]);
+        assert!(!args.crossword);
+
+        let args = parse(&["solve", "-c", "--max-words=3", "-l", "en"])
+            .expect("valid arguments");
+        assert_eq!(args.command, Some(Command::Solve));
+        assert_eq!(args.language.as_deref(), Some("en"));
+        assert_eq!(args.max_words, Some(3));
+        assert!(args.crossword);
+        assert_eq!(args.format, OutputFormat::Text);
+
+        let args = parse(&["simulate", "en", "--", "-x", "words"])
+            .expect("valid arguments");
+        assert_eq!(args.command, Some(Command::Simulate));
+        assert_eq!(args.operands, ["en", "-x", "words"]);
+
+        assert!(parse(&["--max-words", "x"]).is_err());
+        assert!(parse(&["--max-words"]).is_err());
+        assert!(parse(&["--unknown"]).is_err());
+        assert!(parse(&["--format", "xml"]).is_err());
+        assert!(parse(&["--template", "{unknown}"]).is_err());
+
+        let args = parse(&["-t", "letters:3", "en"]).expect("valid arguments");
+        assert_eq!(args.template, ResultFormat::Letters(Some(3)));
+        assert_eq!(args.words_to_collect(None, || 5), Some(0));
+        assert_eq!(args.words_to_collect(Some(2), || 5), Some(2));
+        assert_eq!(
+            parse(&[]).expect("valid").words_to_collect(None, || 5),
+            Some(5)
+        );
+        assert_eq!(
+            parse(&["--template=csv"])
+                .expect("valid")
+                .words_to_collect(None, || 5),
+            None
+        );
+
+        #[cfg(feature = "serde")]
+        assert_eq!(
+            parse(&["-f", "jsonl"]).expect("valid arguments").format,
+            OutputFormat::JsonLines
+        );
+    }
+}
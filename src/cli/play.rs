@@ -0,0 +1,104 @@
+// SPDX-License-Identifier: EUPL-1.2
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use hangman_solver_lib::{DifficultyLevel, Guess, Host, Language, WordPicker};
+
+use crate::cli::{Args, get_terminal_width, prompt};
+
+/// How many wrong guesses the player may make.
+const LIVES: u32 = 10;
+
+fn seed_from_time() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| {
+            duration.as_secs() ^ u64::from(duration.subsec_nanos())
+        })
+}
+
+/// Let the user guess a word picked by the computer.
+pub fn run(args: &Args, language: Language) -> i32 {
+    let level = match args.operands.as_slice() {
+        [] => None,
+        [level] => {
+            let Some(level) = DifficultyLevel::from_string(level) else {
+                eprintln!("Invalid difficulty: {level}");
+                return 1;
+            };
+            Some(level)
+        }
+        _ => {
+            eprintln!("Too many arguments");
+            return 1;
+        }
+    };
+
+    let mut picker = WordPicker::new(language, seed_from_time());
+    let word = match level {
+        Some(level) => picker.pick(4..=12, &level.score_range()),
+        None => picker.pick(4..=12, &(..)),
+    };
+    let Some((word, _)) = word else {
+        eprintln!("No word found");
+        return 1;
+    };
+
+    let mut host = Host::new(language, word, LIVES);
+    println!(
+        "Picked a word with {} letters (difficulty: {}).",
+        word.chars().count(),
+        level.map_or("any", DifficultyLevel::name)
+    );
+    println!("Enter ? for a hint, or an empty line to give up.");
+
+    while !host.is_over() {
+        println!(
+            "{} (lives: {}, guessed: {})",
+            host.pattern(),
+            host.lives(),
+            String::from_iter(host.guessed_letters())
+        );
+        let Some(line) = prompt("Guess a letter: ") else {
+            println!();
+            break;
+        };
+        let line = line.trim();
+
+        if line.is_empty() {
+            break;
+        }
+        if line == "?" {
+            let width = get_terminal_width();
+            let hint = host.hint(args.max_words.or_else(|| {
+                Some(width / (host.pattern().chars().count() + 1) + 1)
+            }));
+            println!("{hint:─^width$}");
+            continue;
+        }
+
+        let mut letters = line.chars();
+        let (Some(letter), None) = (letters.next(), letters.next()) else {
+            println!("Please enter exactly one letter.");
+            continue;
+        };
+
+        match host.guess(letter) {
+            Guess::Hit(count) => {
+                println!("{letter} is {count} times in the word.");
+            }
+            Guess::Miss => println!("{letter} is not in the word."),
+            Guess::AlreadyGuessed => println!("You already guessed {letter}."),
+            Guess::GameOver => break,
+        }
+    }
+
+    if host.is_solved() {
+        println!("{}", host.word());
+        println!("You won!");
+    } else if host.is_lost() {
+        println!("You lost! The word was {}.", host.word());
+    } else {
+        println!("You gave up! The word was {}.", host.word());
+    }
+    0
+}
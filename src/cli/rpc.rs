@@ -0,0 +1,531 @@
+// SPDX-License-Identifier: EUPL-1.2
+//! JSON-RPC 2.0 over stdio, one request or batch per line.
+//!
+//! Methods:
+//! - `solve` `{pattern, invalid?, language?, crossword?, max_words?, offset?, seed?}`
+//! - `languages`
+//! - `words` `{language?, length, offset?, limit?}`
+//! - `session.new` `{language?, length? | pattern?}`
+//! - `session.guess` `{session, letter, positions?}`, positions start at 0,
+//!   no positions are a miss
+//! - `session.undo` `{session}`
+//! - `session.state` `{session, max_words?}`
+//! - `session.close` `{session}`
+use std::collections::HashMap;
+use std::io::{self, BufRead, Write};
+
+use hangman_solver_lib::{Language, Session, WordSelection, solve_infallible};
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use serde_json::{Value, json};
+
+use crate::cli::Args;
+
+const PARSE_ERROR: i64 = -32700;
+const INVALID_REQUEST: i64 = -32600;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+/// The request was valid, but couldn't be executed.
+const SERVER_ERROR: i64 = -32000;
+
+/// The count of words returned if the request doesn't limit them.
+const DEFAULT_MAX_WORDS: usize = 100;
+
+struct Error {
+    code: i64,
+    message: String,
+}
+
+impl Error {
+    fn new(code: i64, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
+}
+
+type RpcResult = Result<Value, Error>;
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SolveParams {
+    pattern: String,
+    #[serde(default)]
+    invalid: String,
+    language: Option<Language>,
+    #[serde(default)]
+    crossword: bool,
+    max_words: Option<usize>,
+    #[serde(default)]
+    offset: usize,
+    seed: Option<u64>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct WordsParams {
+    language: Option<Language>,
+    length: usize,
+    #[serde(default)]
+    offset: usize,
+    limit: Option<usize>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct NewSessionParams {
+    language: Option<Language>,
+    length: Option<usize>,
+    pattern: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct GuessParams {
+    session: u64,
+    letter: char,
+    #[serde(default)]
+    positions: Vec<usize>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SessionParams {
+    session: u64,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SessionStateParams {
+    session: u64,
+    max_words: Option<usize>,
+}
+
+/// Handles the requests, keeping the sessions between them.
+pub struct Rpc {
+    /// Used if a request doesn't set a language.
+    default_language: Option<Language>,
+    sessions: HashMap<u64, Session>,
+    next_session_id: u64,
+}
+
+fn params<T: DeserializeOwned>(params: Value) -> Result<T, Error> {
+    serde_json::from_value(params)
+        .map_err(|error| Error::new(INVALID_PARAMS, error.to_string()))
+}
+
+fn to_value(value: &impl serde::Serialize) -> RpcResult {
+    serde_json::to_value(value)
+        .map_err(|error| Error::new(SERVER_ERROR, error.to_string()))
+}
+
+fn session_state(id: u64, session: &Session) -> Value {
+    json!({
+        "session": id,
+        "language": session.language(),
+        "pattern": session.pattern(),
+        "invalid": session.invalid_letters().iter().collect::<String>(),
+        "solved": session.is_solved(),
+        "suggestion": session.suggestion(),
+    })
+}
+
+impl Rpc {
+    pub fn new(default_language: Option<Language>) -> Self {
+        Self {
+            default_language,
+            sessions: HashMap::new(),
+            next_session_id: 1,
+        }
+    }
+
+    fn language(&self, language: Option<Language>) -> Result<Language, Error> {
+        language
+            .or(self.default_language)
+            .ok_or_else(|| Error::new(INVALID_PARAMS, "language is required"))
+    }
+
+    fn session(&mut self, id: u64) -> Result<&mut Session, Error> {
+        self.sessions
+            .get_mut(&id)
+            .ok_or_else(|| Error::new(SERVER_ERROR, "unknown session"))
+    }
+
+    fn solve(&self, params: &SolveParams) -> RpcResult {
+        if params.seed.is_some() && params.offset != 0 {
+            return Err(Error::new(
+                INVALID_PARAMS,
+                "offset can't be used with seed",
+            ));
+        }
+        to_value(&solve_infallible(
+            params.pattern.as_str(),
+            params.invalid.as_str(),
+            !params.crossword,
+            self.language(params.language)?,
+            Some(params.max_words.unwrap_or(DEFAULT_MAX_WORDS)),
+            WordSelection::new(params.offset, params.seed),
+        ))
+    }
+
+    fn words(&self, params: &WordsParams) -> RpcResult {
+        let words = self.language(params.language)?.read_words(params.length);
+        let page: Vec<&str> = words
+            .iter()
+            .skip(params.offset)
+            .take(params.limit.unwrap_or(DEFAULT_MAX_WORDS))
+            .collect();
+        let next_offset = params.offset.saturating_add(page.len());
+
+        Ok(json!({
+            "count": words.len(),
+            "words": page,
+            "next_offset": (next_offset < words.len()).then_some(next_offset),
+        }))
+    }
+
+    fn new_session(&mut self, params: NewSessionParams) -> RpcResult {
+        let language = self.language(params.language)?;
+        let session = match (params.length, params.pattern) {
+            (Some(length), None) => {
+                Session::new(language, length).map_err(|error| {
+                    Error::new(INVALID_PARAMS, error.to_string())
+                })?
+            }
+            (None, Some(pattern)) => Session::with_pattern(language, &pattern),
+            _ => {
+                return Err(Error::new(
+                    INVALID_PARAMS,
+                    "either length or pattern is required",
+                ));
+            }
+        };
+
+        let id = self.next_session_id;
+        self.next_session_id += 1;
+        let state = session_state(id, &session);
+        self.sessions.insert(id, session);
+        Ok(state)
+    }
+
+    fn call(&mut self, method: &str, raw_params: Value) -> RpcResult {
+        match method {
+            "solve" => self.solve(&params(raw_params)?),
+            "languages" => Ok(Language::all()
+                .iter()
+                .map(|language| {
+                    json!({
+                        "name": language.name(),
+                        "max_word_length": language.max_word_length(),
+                    })
+                })
+                .collect()),
+            "words" => self.words(&params(raw_params)?),
+            "session.new" => self.new_session(params(raw_params)?),
+            "session.guess" => {
+                let params: GuessParams = params(raw_params)?;
+                let session = self.session(params.session)?;
+                if params.positions.is_empty() {
+                    session.miss(params.letter)
+                } else {
+                    session.hit(params.letter, &params.positions)
+                }
+                .map_err(|error| Error::new(SERVER_ERROR, error.to_string()))?;
+
+                Ok(session_state(params.session, session))
+            }
+            "session.undo" => {
+                let params: SessionParams = params(raw_params)?;
+                let session = self.session(params.session)?;
+                if !session.undo() {
+                    return Err(Error::new(SERVER_ERROR, "nothing to undo"));
+                }
+
+                Ok(session_state(params.session, session))
+            }
+            "session.state" => {
+                let params: SessionStateParams = params(raw_params)?;
+                let session = self.session(params.session)?;
+                let mut state = session_state(params.session, session);
+                if let Some(object) = state.as_object_mut() {
+                    object.insert(
+                        "result".to_owned(),
+                        to_value(&session.solve(Some(
+                            params.max_words.unwrap_or(DEFAULT_MAX_WORDS),
+                        )))?,
+                    );
+                }
+
+                Ok(state)
+            }
+            "session.close" => {
+                let params: SessionParams = params(raw_params)?;
+                self.sessions.remove(&params.session).ok_or_else(|| {
+                    Error::new(SERVER_ERROR, "unknown session")
+                })?;
+
+                Ok(Value::Bool(true))
+            }
+            _ => Err(Error::new(METHOD_NOT_FOUND, "method not found")),
+        }
+    }
+
+    /// Handle a single request, returns `None` for notifications.
+    fn handle_request(&mut self, request: Value) -> Option<Value> {
+        let Value::Object(mut request) = request else {
+            return Some(error_response(
+                &Value::Null,
+                &Error::new(INVALID_REQUEST, "request must be an object"),
+            ));
+        };
+        let id = request.remove("id");
+
+        let (Some(version), Some(Value::String(method))) =
+            (request.remove("jsonrpc"), request.remove("method"))
+        else {
+            // invalid requests are answered, even without an id
+            return Some(error_response(
+                &id.unwrap_or(Value::Null),
+                &Error::new(INVALID_REQUEST, "invalid request"),
+            ));
+        };
+        if version != "2.0" {
+            return Some(error_response(
+                &id.unwrap_or(Value::Null),
+                &Error::new(INVALID_REQUEST, "jsonrpc must be \"2.0\""),
+            ));
+        }
+
+        let result = self.call(
+            &method,
+            request.remove("params").unwrap_or_else(|| json!({})),
+        );
+
+        // only valid notifications aren't answered
+        let id = id?;
+        Some(match result {
+            Ok(result) => json!({"jsonrpc": "2.0", "result": result, "id": id}),
+            Err(error) => error_response(&id, &error),
+        })
+    }
+
+    /// Handle a line with a request or a batch of requests.
+    pub fn handle_line(&mut self, line: &str) -> Option<Value> {
+        match serde_json::from_str::<Value>(line) {
+            Err(error) => Some(error_response(
+                &Value::Null,
+                &Error::new(PARSE_ERROR, error.to_string()),
+            )),
+            Ok(Value::Array(requests)) if !requests.is_empty() => {
+                let responses: Vec<Value> = requests
+                    .into_iter()
+                    .filter_map(|request| self.handle_request(request))
+                    .collect();
+                (!responses.is_empty()).then_some(Value::Array(responses))
+            }
+            Ok(request) => self.handle_request(request),
+        }
+    }
+}
+
+fn error_response(id: &Value, error: &Error) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "error": {"code": error.code, "message": error.message},
+        "id": id,
+    })
+}
+
+/// Answer the requests read from stdin, line by line.
+pub fn run(args: &mut Args) -> i32 {
+    let default_language = match args.take_language() {
+        None => None,
+        Some(language) => {
+            let Some(language) = Language::from_string(&language) else {
+                eprintln!("Invalid language");
+                return 1;
+            };
+            Some(language)
+        }
+    };
+    if !args.operands.is_empty() {
+        eprintln!("Too many arguments");
+        return 1;
+    }
+
+    let mut rpc = Rpc::new(default_language);
+    let mut stdout = io::stdout();
+
+    for line in io::stdin().lock().lines() {
+        let line = match line {
+            Ok(line) => line,
+            Err(error) => {
+                eprintln!("{error}");
+                return 1;
+            }
+        };
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        if let Some(response) = rpc.handle_line(&line)
+            && writeln!(stdout, "{response}")
+                .and_then(|()| stdout.flush())
+                .is_err()
+        {
+            return 1;
+        }
+    }
+
+    0
+}
+
+#[cfg(test)]
+mod tests {
+    use hangman_solver_lib::Language;
+    use serde_json::{Value, json};
+
+    use super::Rpc;
+
+    fn call(rpc: &mut Rpc, request: &Value) -> Value {
+        rpc.handle_line(&request.to_string())
+            .expect("request has an id")
+    }
+
+    #[test]
+    fn test_solve() {
+        let mut rpc = Rpc::new(Some(Language::En));
+
+        let response = call(
+            &mut rpc,
+            &json!({
+                "jsonrpc": "2.0", "id": 1, "method": "solve",
+                "params": {"pattern": "te_t", "max_words": 2}
+            }),
+        );
+        assert_eq!(response.pointer("/id"), Some(&json!(1)));
+        assert_eq!(response.pointer("/result/count"), Some(&json!(4)));
+        assert_eq!(
+            response.pointer("/result/words"),
+            Some(&json!(["teat", "tent"]))
+        );
+        let letters: Option<Vec<&str>> = response
+            .pointer("/result/letter_frequency")
+            .and_then(Value::as_object)
+            .map(|letters| letters.keys().map(String::as_str).collect());
+        assert_eq!(letters, Some(vec!["a", "n", "s", "x"]));
+
+        let response = call(
+            &mut rpc,
+            &json!({
+                "jsonrpc": "2.0", "id": 2, "method": "solve",
+                "params": {"pattern": "t__t", "crossword": true,
+                           "language": "de_basic", "max_words": 0}
+            }),
+        );
+        assert_eq!(
+            response.pointer("/result/language"),
+            Some(&json!("de_basic"))
+        );
+
+        let notification = json!({
+            "jsonrpc": "2.0", "method": "solve", "params": {"pattern": "t"}
+        });
+        assert_eq!(rpc.handle_line(&notification.to_string()), None);
+    }
+
+    #[test]
+    fn test_errors() {
+        let mut rpc = Rpc::new(None);
+
+        for (request, code) in [
+            ("{", -32700),
+            (r#"{"jsonrpc": "1.0", "id": 1, "method": "solve"}"#, -32600),
+            (r#"{"jsonrpc": "1.0", "method": "solve"}"#, -32600),
+            (r#"{"jsonrpc": "2.0"}"#, -32600),
+            ("[1]", -32600),
+            (r#"{"jsonrpc": "2.0", "id": 1, "method": "dance"}"#, -32601),
+            (
+                r#"{"jsonrpc": "2.0", "id": 1, "method": "solve",
+                    "params": {"pattern": "t__t"}}"#,
+                -32602,
+            ),
+            (
+                r#"{"jsonrpc": "2.0", "id": 1, "method": "session.undo",
+                    "params": {"session": 1}}"#,
+                -32000,
+            ),
+            (
+                r#"{"jsonrpc": "2.0", "id": 1, "method": "session.undo",
+                    "params": {"session": 1, "max_words": 3}}"#,
+                -32602,
+            ),
+            (
+                r#"{"jsonrpc": "2.0", "id": 1, "method": "session.new",
+                    "params": {"language": "en", "length": 1000000000000}}"#,
+                -32602,
+            ),
+        ] {
+            let response =
+                rpc.handle_line(request).expect("errors are answered");
+            let response = response.get(0).unwrap_or(&response);
+            assert_eq!(response.pointer("/error/code"), Some(&json!(code)));
+            if !request.contains("\"id\"") {
+                assert_eq!(response.get("id"), Some(&Value::Null));
+            }
+        }
+    }
+
+    #[test]
+    fn test_session() {
+        let mut rpc = Rpc::new(None);
+        let mut request = |method: &str, params: Value| {
+            call(
+                &mut rpc,
+                &json!({
+                    "jsonrpc": "2.0", "id": 1, "method": method,
+                    "params": params
+                }),
+            )
+        };
+
+        let response =
+            request("session.new", json!({"language": "en", "length": 4}));
+        let session = response
+            .pointer("/result/session")
+            .cloned()
+            .expect("session id");
+
+        let response = request(
+            "session.guess",
+            json!({"session": session, "letter": "t", "positions": [0, 3]}),
+        );
+        assert_eq!(response.pointer("/result/pattern"), Some(&json!("t__t")));
+
+        let response = request(
+            "session.guess",
+            json!({"session": session, "letter": "a"}),
+        );
+        assert_eq!(response.pointer("/result/invalid"), Some(&json!("a")));
+
+        let response = request("session.state", json!({"session": session}));
+        assert_eq!(
+            response.pointer("/result/result/input"),
+            Some(&json!("t__t"))
+        );
+
+        let response = request("session.undo", json!({"session": session}));
+        assert_eq!(response.pointer("/result/invalid"), Some(&json!("")));
+
+        let batch = json!([
+            {"jsonrpc": "2.0", "id": 1, "method": "languages"},
+            {"jsonrpc": "2.0", "method": "languages"},
+            {"jsonrpc": "2.0", "id": 2, "method": "session.close",
+             "params": {"session": session}},
+        ]);
+        let response = rpc.handle_line(&batch.to_string()).expect("has ids");
+        assert_eq!(response.as_array().map(Vec::len), Some(2));
+        assert_eq!(response.pointer("/1/result"), Some(&json!(true)));
+    }
+}
@@ -0,0 +1,36 @@
+// SPDX-License-Identifier: EUPL-1.2
+use std::io::{self, BufRead};
+
+use hangman_solver_lib::{Language, simulate};
+
+use crate::cli::Args;
+
+/// Let the solver play against the words given as arguments or on stdin.
+pub fn run(args: &Args, language: Language) -> i32 {
+    let words: Box<dyn Iterator<Item = String>> = if args.operands.is_empty() {
+        Box::new(io::stdin().lock().lines().map_while(Result::ok))
+    } else {
+        Box::new(args.operands.clone().into_iter())
+    };
+
+    for word in words {
+        let word = word.trim();
+        if word.is_empty() {
+            continue;
+        }
+        let simulation = simulate(word, language);
+        println!(
+            "{word}: {} misses, {} guesses ({}){}",
+            simulation.misses,
+            simulation.guesses.len(),
+            String::from_iter(&simulation.guesses),
+            if simulation.solved {
+                ""
+            } else {
+                ", not solved"
+            }
+        );
+    }
+
+    0
+}
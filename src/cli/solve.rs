@@ -0,0 +1,94 @@
+// SPDX-License-Identifier: EUPL-1.2
+use std::io::{self, BufRead};
+
+#[cfg(feature = "serde")]
+use hangman_solver_lib::HangmanResult;
+use hangman_solver_lib::InfallibleCharCollection as _;
+use hangman_solver_lib::{
+    Language, ResultFormat, WordSelection, solve_infallible,
+};
+
+use crate::cli::{Args, OutputFormat, get_terminal_width};
+
+/// Solve the patterns read from stdin, one per line.
+pub fn run(args: &Args, language: Language) -> i32 {
+    if !args.operands.is_empty() {
+        eprintln!("Too many arguments");
+        return 1;
+    }
+
+    #[cfg(feature = "serde")]
+    let mut results: Vec<HangmanResult> = Vec::new();
+    let mut buffer = String::new();
+    let stdin = io::stdin();
+
+    if args.format == OutputFormat::Text && args.template == ResultFormat::Csv {
+        println!("{}", ResultFormat::CSV_HEADER);
+    }
+
+    let exit_code = loop {
+        let r = stdin.lock().read_line(&mut buffer);
+        match r {
+            Ok(result) => {
+                if buffer.is_empty() {
+                    break i32::from(result != 0);
+                }
+
+                let width = get_terminal_width();
+
+                let mut input =
+                    buffer.splitn(2, ' ').collect::<Box<[&str]>>().into_iter();
+                let pattern: &str = input.next().unwrap_or("");
+                let invalid: &str = input.next().unwrap_or("");
+                let max_words = args.words_to_collect(args.max_words, || {
+                    width / pattern.char_count() + 1
+                });
+                let hr = solve_infallible(
+                    pattern,
+                    invalid,
+                    !args.crossword,
+                    language,
+                    max_words,
+                    WordSelection::default(),
+                );
+                assert_eq!(hr.language, Some(language));
+
+                match args.format {
+                    OutputFormat::Text => {
+                        println!(
+                            "{:─^width$}",
+                            hr.display_with(&args.template)
+                        );
+                    }
+                    #[cfg(feature = "serde")]
+                    OutputFormat::Json => results.push(hr),
+                    #[cfg(feature = "serde")]
+                    OutputFormat::JsonLines => print_json_line(&hr),
+                }
+            }
+            Err(error) => {
+                eprintln!("{error}");
+            }
+        }
+
+        buffer.clear();
+    };
+
+    #[cfg(feature = "serde")]
+    if args.format == OutputFormat::Json {
+        match serde_json::to_string_pretty(&results) {
+            Ok(json) => println!("{json}"),
+            Err(error) => eprintln!("{error}"),
+        }
+    }
+
+    exit_code
+}
+
+#[cfg(feature = "serde")]
+fn print_json_line(result: &HangmanResult) {
+    match serde_json::to_string(result) {
+        Ok(json) => println!("{json}"),
+        Err(error) => eprintln!("{error}"),
+    }
+}
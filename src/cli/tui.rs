@@ -0,0 +1,377 @@
+// SPDX-License-Identifier: EUPL-1.2
+//! An interactive terminal UI, updating the matching words on every key.
+use std::collections::HashMap;
+use std::io::{self, Write};
+
+use crossterm::cursor::{Hide, MoveTo, Show};
+use crossterm::event::{
+    self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers,
+};
+use crossterm::style::{Color, Print, ResetColor, SetForegroundColor, Stylize};
+use crossterm::terminal::{
+    self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen,
+};
+use crossterm::{execute, queue};
+use hangman_solver_lib::{HangmanResult, Language, Pattern, WordSelection};
+use unwrap_infallible::UnwrapInfallible;
+
+use crate::cli::Args;
+
+/// How many suggested letters are shown.
+const SUGGESTIONS: usize = 8;
+/// The lines above the list of words.
+const HEADER_LINES: u16 = 8;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Field {
+    Pattern,
+    Invalid,
+}
+
+/// The state of the letter on the keyboard.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum LetterState {
+    Revealed,
+    Invalid,
+    Suggested,
+    Unknown,
+}
+
+struct Tui {
+    language: Language,
+    crossword: bool,
+    pattern: String,
+    invalid: String,
+    field: Field,
+    /// The index of the first shown word.
+    scroll: usize,
+    /// The letters in words of a length.
+    alphabets: HashMap<usize, Vec<char>>,
+}
+
+impl Tui {
+    fn new(language: Language, crossword: bool, pattern: String) -> Self {
+        Self {
+            language,
+            crossword,
+            pattern,
+            invalid: String::new(),
+            field: Field::Pattern,
+            scroll: 0,
+            alphabets: HashMap::new(),
+        }
+    }
+
+    fn pattern(&self) -> Pattern {
+        Pattern::new(
+            self.pattern.as_str(),
+            self.invalid.as_str(),
+            !self.crossword,
+        )
+        .unwrap_infallible()
+    }
+
+    fn solve(&self, max_words: usize) -> HangmanResult {
+        self.pattern().solve(
+            self.language,
+            Some(max_words),
+            WordSelection::First {
+                offset: self.scroll,
+            },
+        )
+    }
+
+    /// The letters of all words with the length of the pattern.
+    fn alphabet(&mut self) -> &[char] {
+        let length = self.pattern().pattern().len();
+        let language = self.language;
+
+        self.alphabets.entry(length).or_insert_with(|| {
+            let mut letters: Vec<char> =
+                Pattern::new(&"_".repeat(length), "", true)
+                    .unwrap_infallible()
+                    .solve(language, Some(0), WordSelection::default())
+                    .letter_frequency
+                    .into_iter()
+                    .map(|(letter, _)| letter)
+                    .collect();
+            letters.sort_unstable();
+            letters
+        })
+    }
+
+    /// Handle a key press, returns false to quit.
+    fn handle_key(&mut self, key: KeyEvent) -> bool {
+        let control = key.modifiers.contains(KeyModifiers::CONTROL);
+        let field = match self.field {
+            Field::Pattern => &mut self.pattern,
+            Field::Invalid => &mut self.invalid,
+        };
+
+        match key.code {
+            KeyCode::Esc => return false,
+            KeyCode::Char('c' | 'd') if control => return false,
+            KeyCode::Char('x') if control => self.crossword = !self.crossword,
+            KeyCode::Tab | KeyCode::BackTab | KeyCode::Enter => {
+                self.field = match self.field {
+                    Field::Pattern => Field::Invalid,
+                    Field::Invalid => Field::Pattern,
+                };
+                return true;
+            }
+            KeyCode::Up => {
+                self.scroll = self.scroll.saturating_sub(1);
+                return true;
+            }
+            KeyCode::Down => {
+                self.scroll = self.scroll.saturating_add(1);
+                return true;
+            }
+            KeyCode::PageUp => {
+                self.scroll = self.scroll.saturating_sub(10);
+                return true;
+            }
+            KeyCode::PageDown => {
+                self.scroll = self.scroll.saturating_add(10);
+                return true;
+            }
+            KeyCode::Backspace => {
+                field.pop();
+            }
+            KeyCode::Char(ch) if !control => {
+                if self.field == Field::Pattern || !field.contains(ch) {
+                    field.extend(ch.to_lowercase());
+                }
+            }
+            _ => return true,
+        }
+
+        // the matching words changed
+        self.scroll = 0;
+        true
+    }
+
+    fn letter_state(
+        &self,
+        letter: char,
+        result: &HangmanResult,
+    ) -> LetterState {
+        if result.input.contains(letter) {
+            LetterState::Revealed
+        } else if self.invalid.contains(letter) {
+            LetterState::Invalid
+        } else if result
+            .letter_frequency
+            .iter()
+            .take(SUGGESTIONS)
+            .any(|(ch, _)| *ch == letter)
+        {
+            LetterState::Suggested
+        } else {
+            LetterState::Unknown
+        }
+    }
+
+    fn draw(&mut self, out: &mut impl Write) -> io::Result<()> {
+        let (width, height) = terminal::size()?;
+        let rows = usize::from(height.saturating_sub(HEADER_LINES + 1));
+        let mut result = self.solve(rows);
+        if result.possible_words.is_empty() && self.scroll > 0 {
+            // scrolled past the end
+            let count = usize::try_from(result.matching_words_count)
+                .unwrap_or(usize::MAX);
+            self.scroll = count.saturating_sub(rows);
+            result = self.solve(rows);
+        }
+
+        queue!(out, Clear(ClearType::All), MoveTo(0, 0))?;
+        queue!(
+            out,
+            Print(format!(
+                "Hangman solver ({}, crossword: {})",
+                self.language.name(),
+                if self.crossword { "on" } else { "off" }
+            ))
+        )?;
+
+        for (line, name, field, value) in [
+            (1, "Pattern", Field::Pattern, &self.pattern),
+            (2, "Invalid", Field::Invalid, &self.invalid),
+        ] {
+            let marker = if self.field == field { ">" } else { " " };
+            queue!(
+                out,
+                MoveTo(0, line),
+                Print(format!("{marker} {name}: {value}"))
+            )?;
+        }
+
+        queue!(out, MoveTo(0, 3), Print("  Letters: "))?;
+        let alphabet = self.alphabet().to_vec();
+        for letter in alphabet {
+            let color = match self.letter_state(letter, &result) {
+                LetterState::Revealed => Color::Green,
+                LetterState::Invalid => Color::Red,
+                LetterState::Suggested => Color::Yellow,
+                LetterState::Unknown => Color::Reset,
+            };
+            queue!(out, SetForegroundColor(color), Print(letter), Print(' '))?;
+        }
+        queue!(out, ResetColor)?;
+
+        let suggestions: Vec<String> = result
+            .letter_statistics
+            .iter()
+            .take(SUGGESTIONS)
+            .map(|statistic| {
+                format!(
+                    "{} {:.0}%",
+                    statistic.letter,
+                    statistic.probability * 100.0
+                )
+            })
+            .collect();
+        queue!(
+            out,
+            MoveTo(0, 4),
+            Print(format!("  Suggest: {}", suggestions.join(", ")))
+        )?;
+
+        queue!(
+            out,
+            MoveTo(0, 6),
+            Print(
+                format!(
+                    "Found {} words (showing {}-{})",
+                    result.matching_words_count,
+                    self.scroll
+                        + usize::from(!result.possible_words.is_empty()),
+                    self.scroll + result.possible_words.len(),
+                )
+                .bold()
+            )
+        )?;
+
+        for (line, word) in (HEADER_LINES - 1..).zip(&result.possible_words) {
+            queue!(out, MoveTo(2, line), Print(word))?;
+        }
+
+        let help = "Tab: switch field  ↑↓ PgUp PgDn: scroll  Ctrl-X: crossword  Esc: quit";
+        queue!(
+            out,
+            MoveTo(0, height.saturating_sub(1)),
+            Print(
+                help.chars()
+                    .take(usize::from(width))
+                    .collect::<String>()
+                    .dim()
+            )
+        )?;
+        out.flush()
+    }
+}
+
+/// Restores the terminal when dropped, even after an error.
+struct RawTerminal;
+
+impl RawTerminal {
+    fn enter() -> io::Result<Self> {
+        terminal::enable_raw_mode()?;
+        execute!(io::stdout(), EnterAlternateScreen, Hide)?;
+        Ok(Self)
+    }
+}
+
+impl Drop for RawTerminal {
+    fn drop(&mut self) {
+        let _ = execute!(io::stdout(), Show, LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+fn run_tui(tui: &mut Tui) -> io::Result<()> {
+    let _terminal = RawTerminal::enter()?;
+    let mut stdout = io::stdout();
+
+    loop {
+        tui.draw(&mut stdout)?;
+        match event::read()? {
+            Event::Key(key)
+                if key.kind != KeyEventKind::Release
+                    && !tui.handle_key(key) =>
+            {
+                return Ok(());
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Run the terminal UI, the optional argument is the initial pattern.
+pub fn run(args: &Args, language: Language) -> i32 {
+    let pattern = match args.operands.as_slice() {
+        [] => String::new(),
+        [pattern] => pattern.parse::<usize>().map_or_else(
+            |_| pattern.to_lowercase(),
+            |length| "_".repeat(length),
+        ),
+        _ => {
+            eprintln!("Too many arguments");
+            return 1;
+        }
+    };
+
+    let mut tui = Tui::new(language, args.crossword, pattern);
+    if let Err(error) = run_tui(&mut tui) {
+        eprintln!("{error}");
+        return 1;
+    }
+    0
+}
+
+#[cfg(test)]
+mod tests {
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use hangman_solver_lib::Language;
+
+    use super::{Field, LetterState, Tui};
+
+    fn press(tui: &mut Tui, code: KeyCode) -> bool {
+        tui.handle_key(KeyEvent::new(code, KeyModifiers::NONE))
+    }
+
+    #[test]
+    fn test_handle_key() {
+        let mut tui = Tui::new(Language::En, false, String::new());
+
+        for ch in "T__T".chars() {
+            assert!(press(&mut tui, KeyCode::Char(ch)));
+        }
+        assert!(press(&mut tui, KeyCode::Tab));
+        assert_eq!(tui.field, Field::Invalid);
+        for ch in "aea".chars() {
+            assert!(press(&mut tui, KeyCode::Char(ch)));
+        }
+        assert!(press(&mut tui, KeyCode::Backspace));
+        assert_eq!(tui.pattern, "t__t");
+        assert_eq!(tui.invalid, "a");
+
+        assert!(press(&mut tui, KeyCode::Down));
+        assert_eq!(tui.scroll, 1);
+        assert!(press(&mut tui, KeyCode::Char('o')));
+        assert_eq!(tui.scroll, 0);
+
+        let result = tui.solve(100);
+        assert!(result.possible_words.contains(&"tilt".into()));
+        assert_eq!(tui.letter_state('t', &result), LetterState::Revealed);
+        assert_eq!(tui.letter_state('a', &result), LetterState::Invalid);
+        assert_eq!(tui.letter_state('i', &result), LetterState::Suggested);
+        assert!(tui.alphabet().contains(&'q'));
+
+        assert!(!tui.handle_key(KeyEvent::new(
+            KeyCode::Char('c'),
+            KeyModifiers::CONTROL
+        )));
+        assert!(!press(&mut tui, KeyCode::Esc));
+    }
+}
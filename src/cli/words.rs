@@ -0,0 +1,329 @@
+// SPDX-License-Identifier: EUPL-1.2
+//! Inspect the word lists of a language.
+use hangman_solver_lib::{
+    Language, WordSelection, normalise_word, solve_infallible,
+};
+
+use crate::cli::{Args, get_terminal_width};
+
+const USAGE: &str = "\
+Usage: hangman_solver words LANGUAGE [ACTION] [ARGS]...
+
+Actions:
+  list [LENGTH]...     Print the words with the lengths, or all words (default)
+  count [LENGTH]...    Print the count of words with the lengths, or all words
+  contains WORD...     Check if the words are in the word list
+  get LENGTH INDEX     Print the word at the index of the words with the length
+  histogram            Print the count of words for every length
+  letters LENGTH       Print the letter statistics of the words with the length
+  help                 Print this help";
+
+fn parse_length(length: &str) -> Result<usize, String> {
+    length
+        .parse()
+        .map_err(|_| format!("Invalid word length: {length}"))
+}
+
+fn parse_lengths(
+    lengths: &[String],
+    language: Language,
+) -> Result<Vec<usize>, String> {
+    if lengths.is_empty() {
+        return Ok((0..=language.max_word_length()).collect());
+    }
+
+    lengths.iter().map(|length| parse_length(length)).collect()
+}
+
+fn list(args: &Args, language: Language, lengths: &[usize]) -> i32 {
+    let mut remaining = args.max_words.unwrap_or(usize::MAX);
+    for length in lengths {
+        for word in language.words(*length).take(remaining) {
+            println!("{word}");
+            remaining -= 1;
+        }
+    }
+
+    0
+}
+
+fn count_words(language: Language, lengths: &[usize]) -> usize {
+    lengths
+        .iter()
+        .map(|length| language.read_words(*length).len())
+        .sum()
+}
+
+fn count(language: Language, lengths: &[usize]) -> i32 {
+    println!("{}", count_words(language, lengths));
+
+    0
+}
+
+/// The words normalised like the words of the word lists,
+/// and whether they are in the word list.
+fn find_words(language: Language, words: &[String]) -> Vec<(String, bool)> {
+    words
+        .iter()
+        .map(|word| {
+            let word = normalise_word(word);
+            let found =
+                language.read_words(word.chars().count()).contains(&word);
+            (word, found)
+        })
+        .collect()
+}
+
+fn contains(language: Language, words: &[String]) -> i32 {
+    let mut all_found = true;
+
+    for (word, found) in find_words(language, words) {
+        all_found &= found;
+        println!("{word}: {}", if found { "yes" } else { "no" });
+    }
+
+    i32::from(!all_found)
+}
+
+fn get_word(
+    language: Language,
+    length: usize,
+    index: &str,
+) -> Result<&'static str, String> {
+    let mut words = language.words(length);
+    let count = words.len();
+
+    index
+        .parse::<usize>()
+        .ok()
+        .and_then(|index| words.nth(index))
+        .ok_or_else(|| format!("Invalid index, there are {count} words"))
+}
+
+fn get(language: Language, length: usize, index: &str) -> i32 {
+    match get_word(language, length, index) {
+        Ok(word) => {
+            println!("{word}");
+            0
+        }
+        Err(error) => {
+            eprintln!("{error}");
+            1
+        }
+    }
+}
+
+fn histogram_lines(language: Language, bar_width: usize) -> Vec<String> {
+    let counts: Vec<(usize, usize)> = (0..=language.max_word_length())
+        .map(|length| (length, language.read_words(length).len()))
+        .filter(|(_, count)| *count > 0)
+        .collect();
+    let max_count = counts.iter().map(|(_, count)| *count).max().unwrap_or(1);
+
+    counts
+        .into_iter()
+        .map(|(length, count)| {
+            let bar = "█".repeat(count * bar_width / max_count);
+            format!("{length:>3} {count:>8} {bar}")
+        })
+        .collect()
+}
+
+fn histogram(language: Language) -> i32 {
+    let bar_width = get_terminal_width().saturating_sub(16).max(1);
+    for line in histogram_lines(language, bar_width) {
+        println!("{line}");
+    }
+
+    0
+}
+
+fn letters_lines(language: Language, length: usize) -> Vec<String> {
+    if length > language.max_word_length() {
+        return vec![format!("0 words with {length} letters")];
+    }
+
+    let result = solve_infallible(
+        &"_".repeat(length),
+        "",
+        true,
+        language,
+        Some(0),
+        WordSelection::default(),
+    );
+
+    let mut lines = vec![format!(
+        "{} words with {length} letters",
+        result.matching_words_count
+    )];
+    for (statistic, (_, words)) in result
+        .letter_statistics
+        .iter()
+        .zip(&result.letter_frequency)
+    {
+        lines.push(format!(
+            "{} {words:>8} {:>6.2}% of words, {:>5.2} per word",
+            statistic.letter,
+            statistic.probability * 100.0,
+            statistic.expected_reveals,
+        ));
+    }
+
+    lines
+}
+
+fn letters(language: Language, length: usize) -> i32 {
+    for line in letters_lines(language, length) {
+        println!("{line}");
+    }
+
+    0
+}
+
+/// Inspect the word lists, the action is the first operand.
+pub fn run(args: &Args, language: Language) -> i32 {
+    let (action, operands) = match args.operands.split_first() {
+        Some((action, operands)) if action.parse::<usize>().is_err() => {
+            (action.as_str(), operands)
+        }
+        // lengths without an action list the words
+        _ => ("list", args.operands.as_slice()),
+    };
+
+    let result = match (action, operands) {
+        ("list", lengths) => parse_lengths(lengths, language)
+            .map(|lengths| list(args, language, &lengths)),
+        ("count", lengths) => parse_lengths(lengths, language)
+            .map(|lengths| count(language, &lengths)),
+        ("contains", words) if !words.is_empty() => {
+            Ok(contains(language, words))
+        }
+        ("get", [length, index]) => {
+            parse_length(length).map(|length| get(language, length, index))
+        }
+        ("help", []) => {
+            println!("{USAGE}");
+            Ok(0)
+        }
+        ("histogram", []) => Ok(histogram(language)),
+        ("letters", [length]) => {
+            parse_length(length).map(|length| letters(language, length))
+        }
+        _ => Err(USAGE.to_owned()),
+    };
+
+    result.unwrap_or_else(|error| {
+        eprintln!("{error}");
+        2
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use hangman_solver_lib::Language;
+
+    use super::{
+        count_words, find_words, get_word, histogram_lines, letters_lines,
+        parse_lengths,
+    };
+
+    #[test]
+    fn test_parse_lengths() {
+        let all = parse_lengths(&[], Language::En).expect("valid");
+        assert_eq!(all.first(), Some(&0));
+        assert_eq!(all.last(), Some(&Language::En.max_word_length()));
+
+        assert_eq!(
+            parse_lengths(&["4".to_owned(), "0".to_owned()], Language::En),
+            Ok(vec![4, 0])
+        );
+        assert!(parse_lengths(&["x".to_owned()], Language::En).is_err());
+    }
+
+    #[test]
+    fn test_count_words() {
+        let words_with_4_letters = Language::En.read_words(4).len();
+        assert!(words_with_4_letters > 0);
+
+        assert_eq!(count_words(Language::En, &[4]), words_with_4_letters);
+        assert_eq!(count_words(Language::En, &[0]), 0);
+        assert_eq!(count_words(Language::En, &[]), 0);
+        assert_eq!(count_words(Language::En, &[usize::MAX]), 0);
+        assert_eq!(
+            count_words(Language::En, &[4, 4]),
+            2 * words_with_4_letters
+        );
+    }
+
+    #[test]
+    fn test_find_words() {
+        let words = ["Test", " test\n", "tset", ""].map(String::from);
+
+        assert_eq!(
+            find_words(Language::En, &words),
+            [
+                ("test".to_owned(), true),
+                ("test".to_owned(), true),
+                ("tset".to_owned(), false),
+                (String::new(), false),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_get_word() {
+        let words = Language::En.read_words(4);
+
+        assert_eq!(
+            get_word(Language::En, 4, "0"),
+            Ok(words.get(0).unwrap_or(""))
+        );
+        let last = (words.len() - 1).to_string();
+        assert!(get_word(Language::En, 4, &last).is_ok());
+
+        let error = format!("Invalid index, there are {} words", words.len());
+        assert_eq!(
+            get_word(Language::En, 4, &words.len().to_string()),
+            Err(error.clone())
+        );
+        assert_eq!(get_word(Language::En, 4, "-1"), Err(error.clone()));
+        assert_eq!(get_word(Language::En, 4, "x"), Err(error));
+        assert_eq!(
+            get_word(Language::En, 0, "0"),
+            Err("Invalid index, there are 0 words".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_histogram_lines() {
+        let lines = histogram_lines(Language::En, 10);
+
+        // lengths without words are skipped
+        assert!(!lines.iter().any(|line| line.starts_with("  0 ")));
+        assert!(lines.iter().any(|line| line.ends_with(&"█".repeat(10))));
+        let line = lines
+            .iter()
+            .find(|line| line.starts_with("  4 "))
+            .expect("there are words with 4 letters");
+        assert!(line.contains(&Language::En.read_words(4).len().to_string()));
+    }
+
+    #[test]
+    fn test_letters_lines() {
+        let lines = letters_lines(Language::En, 4);
+        assert_eq!(
+            lines.first(),
+            Some(&format!(
+                "{} words with 4 letters",
+                Language::En.read_words(4).len()
+            ))
+        );
+        assert!(lines.len() > 1);
+
+        assert_eq!(letters_lines(Language::En, 0), ["0 words with 0 letters"]);
+        assert_eq!(
+            letters_lines(Language::En, usize::MAX),
+            [format!("0 words with {} letters", usize::MAX)]
+        );
+    }
+}
@@ -0,0 +1,320 @@
+// SPDX-License-Identifier: EUPL-1.2
+use std::collections::HashMap;
+use std::iter::zip;
+use std::ops::{Range, RangeBounds, RangeInclusive};
+
+use unwrap_infallible::UnwrapInfallible;
+
+use crate::language::{Language, StringChunkIter, WordSequence};
+use crate::solver::random::Random;
+use crate::solver::{LetterStatistic, Pattern, WordSelection};
+
+/// How often the picker tries a random word before giving up.
+const MAX_PICK_ATTEMPTS: usize = 1000;
+
+/// The result of the built-in solver playing hangman.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Simulation {
+    /// The guessed letters, in order.
+    pub guesses: Vec<char>,
+    /// The count of guessed letters not in the word.
+    pub misses: u32,
+    /// false if the solver ran out of words before solving the word.
+    pub solved: bool,
+}
+
+/// Let the built-in solver play hangman in normal mode against a word.
+///
+/// The solver always guesses the most frequent letter.
+#[must_use]
+pub fn simulate(word: &str, language: Language) -> Simulation {
+    let word: Vec<char> = word.chars().flat_map(char::to_lowercase).collect();
+    let mut pattern: Vec<char> = vec!['_'; word.len()];
+    let mut invalid: Vec<char> = vec![];
+    let mut guesses: Vec<char> = vec![];
+    let mut misses = 0;
+
+    while pattern.contains(&'_') {
+        let result = Pattern::new(&pattern, &invalid, true)
+            .unwrap_infallible()
+            .solve(language, Some(0), WordSelection::default());
+
+        let Some(&(guess, _)) = result.letter_frequency.first() else {
+            return Simulation {
+                guesses,
+                misses,
+                solved: false,
+            };
+        };
+
+        guesses.push(guess);
+
+        if word.contains(&guess) {
+            for (p, ch) in zip(pattern.iter_mut(), &word) {
+                if *ch == guess {
+                    *p = guess;
+                }
+            }
+        } else {
+            misses += 1;
+            invalid.push(guess);
+        }
+    }
+
+    Simulation {
+        guesses,
+        misses,
+        solved: true,
+    }
+}
+
+/// How hard a word is to guess.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Difficulty {
+    /// The misses of the built-in solver until the word is solved.
+    pub misses: u32,
+    /// The mean miss probability of the distinct letters of the word
+    /// in words with the same length, between 0 and 1.
+    pub letter_rarity: f64,
+    /// The count of other words with the same length,
+    /// that differ in exactly one position.
+    pub neighbours: u32,
+}
+
+impl Difficulty {
+    /// A single number for the difficulty, higher is harder.
+    ///
+    /// This is the misses of the solver plus the letter rarity
+    /// plus the natural logarithm of one plus the neighbours.
+    #[must_use]
+    pub fn score(&self) -> f64 {
+        f64::from(self.misses)
+            + self.letter_rarity
+            + f64::from(self.neighbours).ln_1p()
+    }
+}
+
+/// Predefined bands of difficulty scores.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DifficultyLevel {
+    Easy,
+    Medium,
+    Hard,
+}
+
+impl DifficultyLevel {
+    /// The range of `Difficulty::score` of this level.
+    #[must_use]
+    pub const fn score_range(self) -> Range<f64> {
+        match self {
+            Self::Easy => 0.0..2.5,
+            Self::Medium => 2.5..5.0,
+            Self::Hard => 5.0..f64::INFINITY,
+        }
+    }
+
+    #[must_use]
+    pub fn from_string(string: &str) -> Option<Self> {
+        match string.to_lowercase().as_str() {
+            "easy" => Some(Self::Easy),
+            "medium" => Some(Self::Medium),
+            "hard" => Some(Self::Hard),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Easy => "easy",
+            Self::Medium => "medium",
+            Self::Hard => "hard",
+        }
+    }
+}
+
+#[inline]
+fn count_neighbours(word: &str, words: &WordSequence) -> u32 {
+    let count = words
+        .iter()
+        .filter(|other| {
+            zip(word.chars(), other.chars())
+                .filter(|(a, b)| a != b)
+                .take(2)
+                .count()
+                == 1
+        })
+        .count();
+
+    u32::try_from(count).unwrap_or(u32::MAX)
+}
+
+/// Picks secret words of a language with a requested difficulty.
+///
+/// The same seed always picks the same words.
+pub struct WordPicker {
+    language: Language,
+    random: Random,
+    /// The letter statistics of all words of a length.
+    letter_statistics: HashMap<usize, Vec<LetterStatistic>>,
+}
+
+impl WordPicker {
+    #[must_use]
+    pub fn new(language: Language, seed: u64) -> Self {
+        Self {
+            language,
+            random: Random::new(seed),
+            letter_statistics: HashMap::new(),
+        }
+    }
+
+    #[must_use]
+    pub const fn language(&self) -> Language {
+        self.language
+    }
+
+    /// Score the difficulty of a word.
+    #[must_use]
+    pub fn difficulty(&mut self, word: &str) -> Difficulty {
+        let length = word.chars().count();
+        let language = self.language;
+        let letter_statistics =
+            self.letter_statistics.entry(length).or_insert_with(|| {
+                Pattern::new(&"_".repeat(length), "", true)
+                    .unwrap_infallible()
+                    .solve(language, Some(0), WordSelection::default())
+                    .letter_statistics
+            });
+
+        let mut letters: Vec<char> = word.chars().collect();
+        letters.sort_unstable();
+        letters.dedup();
+
+        let rarity_sum: f64 = letters
+            .iter()
+            .map(|letter| {
+                letter_statistics
+                    .iter()
+                    .find(|statistic| statistic.letter == *letter)
+                    .map_or(1.0, |statistic| statistic.miss_probability)
+            })
+            .sum();
+
+        Difficulty {
+            misses: simulate(word, language).misses,
+            letter_rarity: if letters.is_empty() {
+                0.0
+            } else {
+                rarity_sum
+                    / f64::from(
+                        u32::try_from(letters.len()).unwrap_or(u32::MAX),
+                    )
+            },
+            neighbours: count_neighbours(word, &language.read_words(length)),
+        }
+    }
+
+    /// Pick a random word with a length in `lengths` and a score in `scores`.
+    ///
+    /// Returns `None` if no fitting word was found after some attempts.
+    pub fn pick(
+        &mut self,
+        lengths: RangeInclusive<usize>,
+        scores: &impl RangeBounds<f64>,
+    ) -> Option<(&'static str, Difficulty)> {
+        let sequences: Vec<StringChunkIter<'static>> = lengths
+            .map(|length| self.language.words(length))
+            .filter(|words| words.len() > 0)
+            .collect();
+        let words_count: usize =
+            sequences.iter().map(ExactSizeIterator::len).sum();
+
+        if words_count == 0 {
+            return None;
+        }
+
+        for _ in 0..MAX_PICK_ATTEMPTS {
+            let mut index = self.random.below(words_count);
+            let Some(word) = sequences.iter().find_map(|words| {
+                if let Some(word) = words.clone().nth(index) {
+                    Some(word)
+                } else {
+                    index -= words.len();
+                    None
+                }
+            }) else {
+                continue;
+            };
+
+            let difficulty = self.difficulty(word);
+            if scores.contains(&difficulty.score()) {
+                return Some((word, difficulty));
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+
+    use super::{DifficultyLevel, WordPicker, simulate};
+    use crate::Language;
+
+    #[test]
+    fn test_simulate() {
+        for word in ["test", "hangman", "epidemic"] {
+            let simulation = simulate(word, Language::En);
+
+            assert!(simulation.solved);
+            assert!(simulation.guesses.iter().all_unique());
+            assert_eq!(
+                usize::try_from(simulation.misses).expect("fits"),
+                simulation
+                    .guesses
+                    .iter()
+                    .filter(|guess| !word.contains(**guess))
+                    .count()
+            );
+            assert!(word.chars().all(|ch| simulation.guesses.contains(&ch)));
+        }
+
+        assert!(!simulate("xqxqxqxq", Language::En).solved);
+    }
+
+    #[test]
+    fn test_difficulty() {
+        let mut picker = WordPicker::new(Language::En, 0);
+
+        let difficulty = picker.difficulty("test");
+        assert!(difficulty.neighbours > 0);
+        assert!((0.0..=1.0).contains(&difficulty.letter_rarity));
+        assert!(difficulty.score() >= f64::from(difficulty.misses));
+        assert_eq!(difficulty, picker.difficulty("test"));
+    }
+
+    #[test]
+    fn test_pick() {
+        let (word, difficulty) = WordPicker::new(Language::En, 42)
+            .pick(8..=9, &(..))
+            .expect("there are words with 8 or 9 letters");
+
+        assert!((8..=9).contains(&word.chars().count()));
+        assert!(Language::En.read_words(word.chars().count()).contains(word));
+
+        let score = difficulty.score();
+        assert_eq!(
+            WordPicker::new(Language::En, 42).pick(8..=9, &(score..=score)),
+            Some((word, difficulty))
+        );
+
+        assert_eq!(
+            WordPicker::new(Language::En, 1)
+                .pick(1000..=1000, &DifficultyLevel::Easy.score_range()),
+            None
+        );
+    }
+}
@@ -0,0 +1,400 @@
+// SPDX-License-Identifier: EUPL-1.2
+use std::collections::BTreeMap;
+
+#[cfg(feature = "wasm-languages")]
+use js_sys::JsString;
+#[cfg(feature = "wasm-languages")]
+use wasm_bindgen::prelude::*;
+
+use crate::language::Language;
+#[cfg(feature = "wasm-languages")]
+use crate::solver::WasmHangmanResult;
+use crate::solver::{HangmanResult, WordSelection, solve_infallible};
+
+/// The outcome of guessing a letter.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Guess {
+    /// The word contains the letter this many times.
+    Hit(u32),
+    /// The word doesn't contain the letter, a life was lost.
+    Miss,
+    /// The letter was guessed before, nothing changed.
+    AlreadyGuessed,
+    /// The game is already won or lost, nothing changed.
+    GameOver,
+}
+
+/// The kind of a `Guess`, for JavaScript.
+#[cfg(feature = "wasm-languages")]
+#[wasm_bindgen]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum GuessKind {
+    Hit,
+    Miss,
+    AlreadyGuessed,
+    GameOver,
+}
+
+/// The outcome of guessing a letter, for JavaScript.
+#[cfg(feature = "wasm-languages")]
+#[wasm_bindgen(js_name = GuessOutcome)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct WasmGuess {
+    #[wasm_bindgen(readonly)]
+    pub kind: GuessKind,
+    /// How often the word contains the letter, 0 if it isn't a hit.
+    #[wasm_bindgen(readonly)]
+    pub count: u32,
+}
+
+#[cfg(feature = "wasm-languages")]
+impl From<Guess> for WasmGuess {
+    fn from(guess: Guess) -> Self {
+        let (kind, count) = match guess {
+            Guess::Hit(count) => (GuessKind::Hit, count),
+            Guess::Miss => (GuessKind::Miss, 0),
+            Guess::AlreadyGuessed => (GuessKind::AlreadyGuessed, 0),
+            Guess::GameOver => (GuessKind::GameOver, 0),
+        };
+        Self { kind, count }
+    }
+}
+
+/// Hosts a game of hangman with a secret word.
+#[cfg_attr(feature = "wasm-languages", wasm_bindgen(js_name = GameHost))]
+#[derive(Clone, Debug)]
+pub struct Host {
+    language: Language,
+    word: Box<[char]>,
+    guessed: Vec<char>,
+    lives: u32,
+    /// The words the word can still be changed to, `None` if it is fixed.
+    candidates: Option<Vec<&'static str>>,
+}
+
+impl Host {
+    /// Host a game with the word, the player may miss `lives` times.
+    #[must_use]
+    pub fn new(language: Language, word: &str, lives: u32) -> Self {
+        Self {
+            language,
+            word: word.chars().flat_map(char::to_lowercase).collect(),
+            guessed: Vec::new(),
+            lives,
+            candidates: None,
+        }
+    }
+
+    /// Host a game without committing to a word with `length` letters.
+    ///
+    /// Every guess keeps the largest group of the words still possible,
+    /// preferring misses, so the word is only fixed when it's revealed.
+    /// `None` if the language has no words with `length` letters.
+    #[must_use]
+    pub fn evil(language: Language, length: usize, lives: u32) -> Option<Self> {
+        let candidates: Vec<&'static str> = language.words(length).collect();
+        let word = candidates.first()?.chars().collect();
+
+        Some(Self {
+            language,
+            word,
+            guessed: Vec::new(),
+            lives,
+            candidates: Some(candidates),
+        })
+    }
+
+    /// Keep the largest group of candidates with the same positions of the
+    /// letter and change the word to one of them.
+    fn keep_largest_group(&mut self, letter: char) {
+        let Some(candidates) = self.candidates.take() else {
+            return;
+        };
+
+        let mut groups: BTreeMap<Vec<usize>, Vec<&'static str>> =
+            BTreeMap::new();
+        for word in candidates {
+            let positions = word
+                .chars()
+                .enumerate()
+                .filter(|(_, ch)| *ch == letter)
+                .map(|(position, _)| position)
+                .collect();
+            groups.entry(positions).or_default().push(word);
+        }
+
+        let candidates = groups
+            .into_iter()
+            .max_by_key(|(positions, words)| {
+                (words.len(), positions.is_empty())
+            })
+            .map(|(_, words)| words)
+            .unwrap_or_default();
+        if let Some(word) = candidates.first() {
+            self.word = word.chars().collect();
+        }
+        self.candidates = Some(candidates);
+    }
+
+    #[must_use]
+    pub const fn language(&self) -> Language {
+        self.language
+    }
+
+    /// The secret word, for an evil host one of the words still possible.
+    #[must_use]
+    pub fn word(&self) -> String {
+        self.word.iter().collect()
+    }
+
+    /// The word with `_` in place of the letters not guessed yet.
+    #[must_use]
+    pub fn pattern(&self) -> String {
+        self.word
+            .iter()
+            .map(|ch| if self.guessed.contains(ch) { *ch } else { '_' })
+            .collect()
+    }
+
+    /// The guessed letters, in order.
+    #[must_use]
+    pub fn guessed_letters(&self) -> &[char] {
+        &self.guessed
+    }
+
+    /// The guessed letters not in the word.
+    #[must_use]
+    pub fn invalid_letters(&self) -> Vec<char> {
+        self.guessed
+            .iter()
+            .filter(|letter| !self.word.contains(letter))
+            .copied()
+            .collect()
+    }
+
+    /// How many more misses the player may make.
+    #[must_use]
+    pub const fn lives(&self) -> u32 {
+        self.lives
+    }
+
+    #[must_use]
+    pub fn is_solved(&self) -> bool {
+        self.word.iter().all(|ch| self.guessed.contains(ch))
+    }
+
+    #[must_use]
+    pub fn is_lost(&self) -> bool {
+        self.lives == 0 && !self.is_solved()
+    }
+
+    #[must_use]
+    pub fn is_over(&self) -> bool {
+        self.lives == 0 || self.is_solved()
+    }
+
+    /// Guess a letter of the word.
+    pub fn guess(&mut self, letter: char) -> Guess {
+        let letter = letter.to_lowercase().next().unwrap_or(letter);
+
+        if self.is_over() {
+            return Guess::GameOver;
+        }
+        if self.guessed.contains(&letter) {
+            return Guess::AlreadyGuessed;
+        }
+        self.guessed.push(letter);
+        self.keep_largest_group(letter);
+
+        let count = self.word.iter().filter(|ch| **ch == letter).count();
+        if count == 0 {
+            self.lives -= 1;
+            Guess::Miss
+        } else {
+            Guess::Hit(u32::try_from(count).unwrap_or(u32::MAX))
+        }
+    }
+
+    /// Solve the current pattern with the guessed letters.
+    pub fn hint(&self, max_words_to_collect: Option<usize>) -> HangmanResult {
+        solve_infallible(
+            &self.pattern(),
+            &self.invalid_letters(),
+            true,
+            self.language,
+            max_words_to_collect,
+            WordSelection::default(),
+        )
+    }
+}
+
+/// The JavaScript API, it needs the `wasm-languages` feature to give hints
+/// from the embedded words of the language.
+#[cfg(feature = "wasm-languages")]
+#[wasm_bindgen(js_class = GameHost)]
+impl Host {
+    /// Host a game with the word, the player may miss `lives` times.
+    #[wasm_bindgen(constructor)]
+    pub fn js_new(
+        language: &str,
+        word: &str,
+        lives: u32,
+    ) -> Result<Self, JsValue> {
+        Ok(Self::new(Language::from_js_name(language)?, word, lives))
+    }
+
+    /// Host a game without committing to a word with `length` letters,
+    /// see `Host::evil`.
+    #[wasm_bindgen(js_name = evil)]
+    pub fn js_evil(
+        language: &str,
+        length: usize,
+        lives: u32,
+    ) -> Result<Self, JsValue> {
+        Self::evil(Language::from_js_name(language)?, length, lives).ok_or_else(
+            || JsValue::from_str(&format!("no words with {length} letters")),
+        )
+    }
+
+    #[wasm_bindgen(getter = language)]
+    #[must_use]
+    pub fn js_language(&self) -> JsString {
+        JsString::from(self.language.name())
+    }
+
+    /// The secret word, for an evil host one of the words still possible.
+    #[wasm_bindgen(js_name = word)]
+    #[must_use]
+    pub fn js_word(&self) -> String {
+        self.word()
+    }
+
+    /// The word with `_` in place of the letters not guessed yet.
+    #[wasm_bindgen(getter = pattern)]
+    #[must_use]
+    pub fn js_pattern(&self) -> String {
+        self.pattern()
+    }
+
+    /// The guessed letters, in order.
+    #[wasm_bindgen(getter = guessedLetters)]
+    #[must_use]
+    pub fn js_guessed_letters(&self) -> Vec<JsString> {
+        self.guessed.iter().copied().map(JsString::from).collect()
+    }
+
+    /// The guessed letters not in the word.
+    #[wasm_bindgen(getter = invalidLetters)]
+    #[must_use]
+    pub fn js_invalid_letters(&self) -> Vec<JsString> {
+        self.invalid_letters()
+            .into_iter()
+            .map(JsString::from)
+            .collect()
+    }
+
+    /// How many more misses the player may make.
+    #[wasm_bindgen(getter = lives)]
+    #[must_use]
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn js_lives(&self) -> u32 {
+        self.lives
+    }
+
+    #[wasm_bindgen(getter = isSolved)]
+    #[must_use]
+    pub fn js_is_solved(&self) -> bool {
+        self.is_solved()
+    }
+
+    #[wasm_bindgen(getter = isLost)]
+    #[must_use]
+    pub fn js_is_lost(&self) -> bool {
+        self.is_lost()
+    }
+
+    #[wasm_bindgen(getter = isOver)]
+    #[must_use]
+    pub fn js_is_over(&self) -> bool {
+        self.is_over()
+    }
+
+    /// Guess a letter of the word.
+    #[wasm_bindgen(js_name = guess)]
+    pub fn js_guess(&mut self, letter: char) -> WasmGuess {
+        self.guess(letter).into()
+    }
+
+    /// Solve the current pattern with the guessed letters.
+    #[wasm_bindgen(js_name = hint)]
+    #[must_use]
+    pub fn js_hint(
+        &self,
+        max_words_to_collect: Option<usize>,
+    ) -> WasmHangmanResult {
+        self.hint(max_words_to_collect).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Guess, Host};
+    use crate::Language;
+
+    #[test]
+    fn test_host() {
+        let mut host = Host::new(Language::En, "Test", 2);
+        assert_eq!(host.word(), "test");
+        assert_eq!(host.pattern(), "____");
+
+        assert_eq!(host.guess('T'), Guess::Hit(2));
+        assert_eq!(host.guess('t'), Guess::AlreadyGuessed);
+        assert_eq!(host.guess('a'), Guess::Miss);
+        assert_eq!(host.pattern(), "t__t");
+        assert_eq!(host.guessed_letters(), ['t', 'a']);
+        assert_eq!(host.invalid_letters(), ['a']);
+        assert_eq!(host.lives(), 1);
+
+        let hint = host.hint(None);
+        assert_eq!(hint.input, "t__t");
+        assert!(hint.possible_words.contains(&"test".into()));
+        assert!(!hint.possible_words.contains(&"taut".into()));
+
+        assert_eq!(host.guess('e'), Guess::Hit(1));
+        assert_eq!(host.guess('s'), Guess::Hit(1));
+        assert!(host.is_solved());
+        assert!(host.is_over());
+        assert_eq!(host.guess('x'), Guess::GameOver);
+    }
+
+    #[test]
+    fn test_host_lost() {
+        let mut host = Host::new(Language::En, "test", 1);
+
+        assert_eq!(host.guess('x'), Guess::Miss);
+        assert!(host.is_lost());
+        assert!(host.is_over());
+        assert_eq!(host.guess('t'), Guess::GameOver);
+        assert_eq!(host.pattern(), "____");
+    }
+
+    #[test]
+    fn test_evil_host() {
+        let mut host = Host::evil(Language::En, 4, 3).expect("has words");
+        assert_eq!(host.pattern(), "____");
+        assert!(Host::evil(Language::En, 99, 3).is_none());
+
+        for letter in "etaoinshrdlucmfwypvbgkjqxz".chars() {
+            if host.guess(letter) == Guess::GameOver {
+                break;
+            }
+
+            let hint = host.hint(None);
+            assert!(hint.possible_words.contains(&host.word().as_str().into()));
+        }
+
+        assert!(host.is_lost());
+        assert_eq!(host.invalid_letters().len(), 3);
+        assert_eq!(host.guess('e'), Guess::GameOver);
+    }
+}
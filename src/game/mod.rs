@@ -0,0 +1,13 @@
+// SPDX-License-Identifier: EUPL-1.2
+
+mod difficulty;
+mod host;
+mod session;
+
+pub use difficulty::{
+    Difficulty, DifficultyLevel, Simulation, WordPicker, simulate,
+};
+pub use host::{Guess, Host};
+#[cfg(feature = "wasm-languages")]
+pub use host::{GuessKind, WasmGuess};
+pub use session::{Session, SessionError};
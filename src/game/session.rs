@@ -0,0 +1,582 @@
+// SPDX-License-Identifier: EUPL-1.2
+use std::fmt::{self, Display};
+
+use unwrap_infallible::UnwrapInfallible;
+
+use crate::language::Language;
+use crate::solver::{HangmanResult, Pattern, WordSelection};
+
+#[cfg(feature = "wasm-languages")]
+use js_sys::JsString;
+#[cfg(feature = "pyo3")]
+use pyo3::prelude::*;
+#[cfg(feature = "wasm-languages")]
+use wasm_bindgen::prelude::*;
+
+#[cfg(feature = "wasm-languages")]
+use crate::solver::WasmHangmanResult;
+
+/// Why a guess couldn't be added to a session.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SessionError {
+    /// The letter was already guessed.
+    AlreadyGuessed(char),
+    /// The position is not in the word.
+    PositionOutOfRange(usize),
+    /// Another letter is already at the position.
+    PositionSolved(usize),
+    /// A hit needs at least one position.
+    NoPositions,
+    /// The length is longer than the longest word of the language.
+    TooLong(usize),
+}
+
+impl Display for SessionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AlreadyGuessed(letter) => {
+                write!(f, "{letter} was already guessed")
+            }
+            Self::PositionOutOfRange(position) => {
+                write!(f, "position {position} is not in the word")
+            }
+            Self::PositionSolved(position) => {
+                write!(f, "position {position} is already solved")
+            }
+            Self::NoPositions => write!(f, "a hit needs at least one position"),
+            Self::TooLong(length) => {
+                write!(f, "there are no words with {length} letters")
+            }
+        }
+    }
+}
+
+impl std::error::Error for SessionError {}
+
+/// A guess with the state of the session before it.
+#[derive(Clone, Debug)]
+struct Step {
+    letter: char,
+    /// The positions of the letter, empty for a miss.
+    positions: Vec<usize>,
+    pattern: Vec<char>,
+    invalid: Vec<char>,
+    candidates: Option<Vec<&'static str>>,
+}
+
+/// The state of a game someone else hosts.
+///
+/// Accumulates the revealed letters and the misses, to solve them together.
+/// The words matching after a guess are kept, so every guess only has to
+/// filter the words that matched before.
+#[cfg_attr(
+    feature = "pyo3",
+    pyclass(
+        name = "GameSession",
+        module = "hangman_solver",
+        skip_from_py_object
+    )
+)]
+#[cfg_attr(feature = "wasm-languages", wasm_bindgen(js_name = GameSession))]
+#[derive(Clone, Debug)]
+pub struct Session {
+    language: Language,
+    pattern: Vec<char>,
+    invalid: Vec<char>,
+    /// The sorted matching words, `None` until the first guess.
+    candidates: Option<Vec<&'static str>>,
+    history: Vec<Step>,
+}
+
+impl Session {
+    /// Start a session for a word with `length` letters.
+    ///
+    /// Lengths longer than the longest word of the language are rejected.
+    pub fn new(
+        language: Language,
+        length: usize,
+    ) -> Result<Self, SessionError> {
+        if length > language.max_word_length() {
+            return Err(SessionError::TooLong(length));
+        }
+        Ok(Self::with_pattern(language, &"_".repeat(length)))
+    }
+
+    /// Start a session with already revealed letters, like `"t__t"`.
+    ///
+    /// Whitespace is ignored and all wildcards become `_`.
+    #[must_use]
+    pub fn with_pattern(language: Language, pattern: &str) -> Self {
+        Self {
+            language,
+            pattern: Pattern::new(pattern, "", false)
+                .unwrap_infallible()
+                .pattern()
+                .to_vec(),
+            invalid: Vec::new(),
+            candidates: None,
+            history: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    pub const fn language(&self) -> Language {
+        self.language
+    }
+
+    /// The revealed letters with `_` at unknown positions.
+    #[must_use]
+    pub fn pattern(&self) -> String {
+        self.pattern.iter().collect()
+    }
+
+    /// The guessed letters not in the word, in order.
+    #[must_use]
+    pub fn invalid_letters(&self) -> &[char] {
+        &self.invalid
+    }
+
+    #[must_use]
+    pub fn is_solved(&self) -> bool {
+        !self.pattern.contains(&'_')
+    }
+
+    /// The pattern the session started with.
+    #[must_use]
+    pub fn initial_pattern(&self) -> String {
+        self.history
+            .first()
+            .map_or(&self.pattern, |step| &step.pattern)
+            .iter()
+            .collect()
+    }
+
+    /// The guessed letters with their positions, empty for misses.
+    pub fn guesses(&self) -> impl Iterator<Item = (char, &[usize])> {
+        self.history
+            .iter()
+            .map(|step| (step.letter, step.positions.as_slice()))
+    }
+
+    fn to_solver_pattern(&self) -> Pattern {
+        Pattern::new(&self.pattern, &self.invalid, true).unwrap_infallible()
+    }
+
+    fn push_step(&mut self, letter: char, positions: &[usize]) {
+        self.history.push(Step {
+            letter,
+            positions: positions.to_vec(),
+            pattern: self.pattern.clone(),
+            invalid: self.invalid.clone(),
+            candidates: self.candidates.clone(),
+        });
+    }
+
+    /// Keep only the candidates matching the current state.
+    fn narrow_candidates(&mut self) {
+        let pattern = self.to_solver_pattern();
+
+        if let Some(candidates) = &mut self.candidates {
+            pattern.retain_matches(candidates);
+        } else {
+            self.candidates =
+                Some(pattern.language_matches_iter(self.language).collect());
+        }
+    }
+
+    fn check_not_guessed(&self, letter: char) -> Result<(), SessionError> {
+        if self.invalid.contains(&letter) || self.pattern.contains(&letter) {
+            Err(SessionError::AlreadyGuessed(letter))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Add a letter that isn't in the word.
+    pub fn miss(&mut self, letter: char) -> Result<(), SessionError> {
+        let letter = letter.to_lowercase().next().unwrap_or(letter);
+        self.check_not_guessed(letter)?;
+
+        self.push_step(letter, &[]);
+        self.invalid.push(letter);
+        self.narrow_candidates();
+        Ok(())
+    }
+
+    /// Add a letter at the (zero based) positions in the word.
+    pub fn hit(
+        &mut self,
+        letter: char,
+        positions: &[usize],
+    ) -> Result<(), SessionError> {
+        let letter = letter.to_lowercase().next().unwrap_or(letter);
+        self.check_not_guessed(letter)?;
+        if positions.is_empty() {
+            return Err(SessionError::NoPositions);
+        }
+        for position in positions {
+            match self.pattern.get(*position) {
+                None => {
+                    return Err(SessionError::PositionOutOfRange(*position));
+                }
+                Some('_') => {}
+                Some(_) => return Err(SessionError::PositionSolved(*position)),
+            }
+        }
+
+        self.push_step(letter, positions);
+        for position in positions {
+            if let Some(ch) = self.pattern.get_mut(*position) {
+                *ch = letter;
+            }
+        }
+        self.narrow_candidates();
+        Ok(())
+    }
+
+    /// Revert the last guess, returns false if there was none.
+    pub fn undo(&mut self) -> bool {
+        if let Some(step) = self.history.pop() {
+            self.pattern = step.pattern;
+            self.invalid = step.invalid;
+            self.candidates = step.candidates;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Solve the current pattern with the invalid letters.
+    pub fn solve(&self, max_words_to_collect: Option<usize>) -> HangmanResult {
+        let pattern = self.to_solver_pattern();
+
+        if let Some(candidates) = &self.candidates {
+            pattern.solve_words(
+                candidates,
+                self.language,
+                max_words_to_collect,
+                WordSelection::default(),
+            )
+        } else {
+            pattern.solve(
+                self.language,
+                max_words_to_collect,
+                WordSelection::default(),
+            )
+        }
+    }
+
+    /// The most frequent letter in the unsolved positions of matching words.
+    #[must_use]
+    pub fn suggestion(&self) -> Option<char> {
+        self.solve(Some(0))
+            .letter_frequency
+            .first()
+            .map(|(letter, _)| *letter)
+    }
+}
+
+/// The guessed letters with their positions, like `GameSession.guesses`.
+#[cfg(feature = "pyo3")]
+type PyGuesses = Vec<(char, Vec<usize>)>;
+
+#[cfg(feature = "pyo3")]
+fn py_session_error(error: SessionError) -> PyErr {
+    pyo3::exceptions::PyValueError::new_err(error.to_string())
+}
+
+#[cfg(feature = "pyo3")]
+#[pymethods]
+impl Session {
+    /// Start a session with the revealed letters, like `"t__t"` or `"____"`.
+    #[new]
+    fn py_new(language: Language, pattern: &str) -> Self {
+        Self::with_pattern(language, pattern)
+    }
+
+    #[getter(language)]
+    const fn py_language(&self) -> Language {
+        self.language
+    }
+
+    /// The revealed letters with `_` at unknown positions.
+    #[getter(pattern)]
+    fn py_pattern(&self) -> String {
+        self.pattern()
+    }
+
+    /// The guessed letters not in the word, in order.
+    #[getter(invalid_letters)]
+    fn py_invalid_letters(&self) -> Vec<char> {
+        self.invalid.clone()
+    }
+
+    #[getter(is_solved)]
+    fn py_is_solved(&self) -> bool {
+        self.is_solved()
+    }
+
+    /// The guessed letters with their positions, empty for misses.
+    #[getter(guesses)]
+    fn py_guesses(&self) -> PyGuesses {
+        self.guesses()
+            .map(|(letter, positions)| (letter, positions.to_vec()))
+            .collect()
+    }
+
+    /// Add a guessed letter at the (zero based) positions, without
+    /// positions the letter isn't in the word.
+    #[pyo3(name = "guess", signature = (letter, positions = Vec::new()))]
+    #[allow(clippy::needless_pass_by_value)]
+    fn py_guess(
+        &mut self,
+        py: Python<'_>,
+        letter: char,
+        positions: Vec<usize>,
+    ) -> PyResult<()> {
+        py.detach(|| {
+            if positions.is_empty() {
+                self.miss(letter)
+            } else {
+                self.hit(letter, &positions)
+            }
+        })
+        .map_err(py_session_error)
+    }
+
+    /// Revert the last guess, returns false if there was none.
+    #[pyo3(name = "undo")]
+    fn py_undo(&mut self) -> bool {
+        self.undo()
+    }
+
+    /// Solve the current pattern with the invalid letters.
+    #[pyo3(name = "result", signature = (max_words_to_collect = None))]
+    fn py_result(
+        &self,
+        py: Python<'_>,
+        max_words_to_collect: Option<usize>,
+    ) -> HangmanResult {
+        py.detach(|| self.solve(max_words_to_collect))
+    }
+
+    /// The most frequent letter in the unsolved positions of matching words.
+    #[pyo3(name = "suggestion")]
+    fn py_suggestion(&self, py: Python<'_>) -> Option<char> {
+        py.detach(|| self.suggestion())
+    }
+
+    fn __reduce__<'py>(
+        slf: &Bound<'py, Self>,
+    ) -> (
+        Bound<'py, pyo3::types::PyType>,
+        (Language, String),
+        PyGuesses,
+    ) {
+        let session = slf.borrow();
+        (
+            slf.get_type(),
+            (session.language, session.initial_pattern()),
+            session.py_guesses(),
+        )
+    }
+
+    fn __setstate__(
+        &mut self,
+        py: Python<'_>,
+        guesses: PyGuesses,
+    ) -> PyResult<()> {
+        for (letter, positions) in guesses {
+            self.py_guess(py, letter, positions)?;
+        }
+        Ok(())
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "<GameSession lang={} pattern={} invalid={:?} candidates={}>",
+            self.language.name(),
+            self.pattern(),
+            self.invalid,
+            self.candidates.as_ref().map_or_else(
+                || "?".to_owned(),
+                |words| words.len().to_string()
+            ),
+        )
+    }
+}
+
+/// The JavaScript API, it needs the `wasm-languages` feature to solve with
+/// the embedded words of the language.
+#[cfg(feature = "wasm-languages")]
+#[wasm_bindgen(js_class = GameSession)]
+impl Session {
+    /// Start a session with the revealed letters, like `"t__t"` or `"____"`.
+    #[wasm_bindgen(constructor)]
+    pub fn js_new(language: &str, pattern: &str) -> Result<Self, JsValue> {
+        Ok(Self::with_pattern(
+            Language::from_js_name(language)?,
+            pattern,
+        ))
+    }
+
+    #[wasm_bindgen(getter = language)]
+    #[must_use]
+    pub fn js_language(&self) -> JsString {
+        JsString::from(self.language.name())
+    }
+
+    /// The revealed letters with `_` at unknown positions.
+    #[wasm_bindgen(getter = pattern)]
+    #[must_use]
+    pub fn js_pattern(&self) -> String {
+        self.pattern()
+    }
+
+    /// The guessed letters not in the word, in order.
+    #[wasm_bindgen(getter = invalidLetters)]
+    #[must_use]
+    pub fn js_invalid_letters(&self) -> Vec<JsString> {
+        self.invalid.iter().copied().map(JsString::from).collect()
+    }
+
+    #[wasm_bindgen(getter = isSolved)]
+    #[must_use]
+    pub fn js_is_solved(&self) -> bool {
+        self.is_solved()
+    }
+
+    /// Add a guessed letter at the (zero based) positions, without
+    /// positions the letter isn't in the word.
+    ///
+    /// Returns the suggestion for the next guess.
+    #[wasm_bindgen(js_name = guess)]
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn js_guess(
+        &mut self,
+        letter: char,
+        positions: Vec<usize>,
+    ) -> Result<Option<char>, JsValue> {
+        if positions.is_empty() {
+            self.miss(letter)
+        } else {
+            self.hit(letter, &positions)
+        }
+        .map_err(|error| JsValue::from_str(&error.to_string()))?;
+
+        Ok(self.suggestion())
+    }
+
+    /// Revert the last guess, returns false if there was none.
+    #[wasm_bindgen(js_name = undo)]
+    pub fn js_undo(&mut self) -> bool {
+        self.undo()
+    }
+
+    /// Solve the current pattern with the invalid letters.
+    #[wasm_bindgen(js_name = result)]
+    #[must_use]
+    pub fn js_result(
+        &self,
+        max_words_to_collect: Option<usize>,
+    ) -> WasmHangmanResult {
+        self.solve(max_words_to_collect).into()
+    }
+
+    /// The most frequent letter in the unsolved positions of matching words.
+    #[wasm_bindgen(js_name = suggestion)]
+    #[must_use]
+    pub fn js_suggestion(&self) -> Option<char> {
+        self.suggestion()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Session, SessionError};
+    use crate::Language;
+    use crate::solver::{WordSelection, solve_infallible};
+
+    #[test]
+    fn test_session() {
+        assert!(
+            Session::new(Language::En, usize::MAX)
+                .is_err_and(|error| error == SessionError::TooLong(usize::MAX))
+        );
+        let mut session = Session::new(Language::En, 4)
+            .expect("there are words with 4 letters");
+        assert_eq!(session.pattern(), "____");
+        assert!(session.suggestion().is_some());
+
+        assert_eq!(session.hit('T', &[0, 3]), Ok(()));
+        assert_eq!(session.miss('a'), Ok(()));
+        assert_eq!(session.pattern(), "t__t");
+        assert_eq!(session.invalid_letters(), ['a']);
+
+        assert_eq!(session.miss('t'), Err(SessionError::AlreadyGuessed('t')));
+        assert_eq!(session.miss('a'), Err(SessionError::AlreadyGuessed('a')));
+        assert_eq!(
+            session.hit('e', &[4]),
+            Err(SessionError::PositionOutOfRange(4))
+        );
+        assert_eq!(
+            session.hit('e', &[0]),
+            Err(SessionError::PositionSolved(0))
+        );
+        assert_eq!(session.hit('e', &[]), Err(SessionError::NoPositions));
+
+        let result = session.solve(None);
+        assert!(result.possible_words.contains(&"test".into()));
+        assert!(!result.possible_words.contains(&"taut".into()));
+
+        assert!(session.undo());
+        assert_eq!(session.invalid_letters(), [] as [char; 0]);
+        assert!(session.undo());
+        assert_eq!(session.pattern(), "____");
+        assert!(!session.undo());
+    }
+
+    #[test]
+    fn test_session_with_pattern() {
+        let mut session = Session::with_pattern(Language::En, "TE?T");
+        assert_eq!(session.pattern(), "te_t");
+
+        assert_eq!(session.hit('x', &[2]), Ok(()));
+        assert!(session.is_solved());
+        assert_eq!(session.solve(None).possible_words, ["text"]);
+        assert_eq!(session.suggestion(), None);
+    }
+
+    #[test]
+    fn test_session_narrows_like_solve() {
+        let mut session =
+            Session::with_pattern(Language::DeUmlauts, "__r_____r");
+        assert_eq!(session.miss('a'), Ok(()));
+        assert_eq!(session.hit('e', &[5, 7]), Ok(()));
+        assert_eq!(session.miss('n'), Ok(()));
+        assert_eq!(session.initial_pattern(), "__r_____r");
+        assert_eq!(
+            session.guesses().collect::<Vec<_>>(),
+            [('a', &[][..]), ('e', &[5, 7][..]), ('n', &[][..])]
+        );
+
+        for _ in 0..3 {
+            let expected = solve_infallible(
+                &session.pattern(),
+                session.invalid_letters(),
+                true,
+                Language::DeUmlauts,
+                None,
+                WordSelection::default(),
+            );
+            let result = session.solve(None);
+            assert_eq!(result.possible_words, expected.possible_words);
+            assert_eq!(result.letter_frequency, expected.letter_frequency);
+            assert_eq!(result.input, expected.input);
+            assert_eq!(result.invalid, expected.invalid);
+
+            assert!(session.undo());
+        }
+        assert_eq!(session.guesses().count(), 0);
+    }
+}
@@ -3,13 +3,42 @@
 #[cfg(feature = "pyo3")]
 mod reversed_string_chunk_iter;
 mod string_chunk_iter;
+mod word_list;
 mod word_sequence;
 
 pub use string_chunk_iter::StringChunkIter;
+#[cfg(feature = "wasm-bindgen")]
+pub use word_list::WasmWordList;
+pub use word_list::{WordList, WordSource, normalise_word};
 pub use word_sequence::WordSequence;
 
 include!(concat!(env!("OUT_DIR"), "/language.rs"));
 
+#[cfg(feature = "serde")]
+impl serde::Serialize for Language {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Language {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let name = <std::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+        Self::from_string(&name).ok_or_else(|| {
+            serde::de::Error::invalid_value(
+                serde::de::Unexpected::Str(&name),
+                &"a language name",
+            )
+        })
+    }
+}
+
 #[cfg(feature = "pyo3")]
 pyo3::create_exception!(
     hangman_solver,
@@ -36,6 +65,12 @@ impl Language {
         self.name()
     }
 
+    /// Pickle the language as the attribute of the `Language` class.
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    fn __reduce__(&self) -> String {
+        format!("Language.{self:?}")
+    }
+
     /// Parse a string into a language.
     #[staticmethod]
     #[pyo3(signature = (name, default = None))]
@@ -48,3 +83,17 @@ impl Language {
             .ok_or_else(|| UnknownLanguageError::new_err(name.to_owned()))
     }
 }
+
+#[cfg(feature = "wasm-languages")]
+impl Language {
+    /// Parse a language name from JavaScript, like `de_umlauts`.
+    pub(crate) fn from_js_name(
+        name: &str,
+    ) -> Result<Self, wasm_bindgen::JsValue> {
+        Self::from_string(name).ok_or_else(|| {
+            wasm_bindgen::JsValue::from_str(&format!(
+                "unknown language: {name}"
+            ))
+        })
+    }
+}
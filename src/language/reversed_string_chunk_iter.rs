@@ -1,17 +1,25 @@
 // SPDX-License-Identifier: EUPL-1.2
+use std::ops::Range;
 
 use pyo3::{PyRef, pyclass, pymethods};
 
-use super::StringChunkIter;
+use super::WordSequence;
+use super::string_chunk_iter::PyStringChunkIter;
 
+/// Iterates over the words of a `WordSequence` from the end from Python.
 #[pyclass(skip_from_py_object)]
 pub struct ReversedStringChunkIter {
-    iter: StringChunkIter,
+    pub(super) words: WordSequence,
+    /// The indices of the words not yielded yet
+    pub(super) remaining: Range<usize>,
 }
 
-impl From<StringChunkIter> for ReversedStringChunkIter {
-    fn from(value: StringChunkIter) -> Self {
-        Self { iter: value }
+impl From<WordSequence> for ReversedStringChunkIter {
+    fn from(words: WordSequence) -> Self {
+        Self {
+            remaining: 0..words.len(),
+            words,
+        }
     }
 }
 
@@ -23,18 +31,20 @@ impl ReversedStringChunkIter {
     }
 
     #[must_use]
-    fn __next__(&mut self) -> Option<&'static str> {
-        self.iter.next_back()
+    fn __next__(&mut self) -> Option<&str> {
+        self.words.get(self.remaining.next_back()?)
     }
 
     #[must_use]
     pub fn __len__(&self) -> usize {
-        self.iter.len()
+        self.remaining.len()
     }
 
     #[must_use]
-    #[allow(clippy::needless_pass_by_value)]
-    pub fn __reversed__(slf: PyRef<'_, Self>) -> StringChunkIter {
-        slf.iter.clone()
+    pub fn __reversed__(&self) -> PyStringChunkIter {
+        PyStringChunkIter {
+            words: self.words.clone(),
+            remaining: self.remaining.clone(),
+        }
     }
 }
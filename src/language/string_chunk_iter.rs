@@ -1,35 +1,53 @@
 use std::iter::FusedIterator;
 // SPDX-License-Identifier: EUPL-1.2
 use std::num::NonZeroUsize;
+#[cfg(feature = "pyo3")]
+use std::ops::Range;
+
+#[cfg(feature = "pyo3")]
+use pyo3::{PyRef, pyclass, pymethods};
 
 #[cfg(feature = "pyo3")]
-use pyo3::prelude::*;
+use super::WordSequence;
 
-#[cfg_attr(feature = "pyo3", pyclass(skip_from_py_object))]
 #[derive(Clone)]
-pub struct StringChunkIter {
+pub struct StringChunkIter<'a> {
     pub(super) padded_word_byte_count: NonZeroUsize,
     pub(super) is_ascii: bool,
-    pub(super) string: &'static str,
+    pub(super) string: &'a str,
 }
 
-impl StringChunkIter {
+impl<'a> StringChunkIter<'a> {
+    #[inline]
+    #[must_use]
+    pub(super) const fn new(
+        word_length: usize,
+        string: &'a str,
+        padded_word_byte_count: NonZeroUsize,
+    ) -> Self {
+        Self {
+            is_ascii: word_length == padded_word_byte_count.get(),
+            padded_word_byte_count,
+            string,
+        }
+    }
+
     #[inline]
     const fn remaining_words(&self) -> usize {
         self.string.len() / self.padded_word_byte_count.get()
     }
 }
 
-impl FusedIterator for StringChunkIter {}
+impl FusedIterator for StringChunkIter<'_> {}
 
-impl ExactSizeIterator for StringChunkIter {
+impl ExactSizeIterator for StringChunkIter<'_> {
     fn len(&self) -> usize {
         self.remaining_words()
     }
 }
 
-impl Iterator for StringChunkIter {
-    type Item = &'static str;
+impl<'a> Iterator for StringChunkIter<'a> {
+    type Item = &'a str;
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
@@ -88,7 +106,7 @@ impl Iterator for StringChunkIter {
     }
 }
 
-impl DoubleEndedIterator for StringChunkIter {
+impl DoubleEndedIterator for StringChunkIter<'_> {
     fn next_back(&mut self) -> Option<Self::Item> {
         let pivot = self
             .string
@@ -111,32 +129,51 @@ impl DoubleEndedIterator for StringChunkIter {
     }
 }
 
+/// Iterates over the words of a `WordSequence` from Python.
+#[cfg(feature = "pyo3")]
+#[pyclass(name = "StringChunkIter", skip_from_py_object)]
+pub struct PyStringChunkIter {
+    pub(super) words: WordSequence,
+    /// The indices of the words not yielded yet
+    pub(super) remaining: Range<usize>,
+}
+
+#[cfg(feature = "pyo3")]
+impl From<WordSequence> for PyStringChunkIter {
+    fn from(words: WordSequence) -> Self {
+        Self {
+            remaining: 0..words.len(),
+            words,
+        }
+    }
+}
+
 #[cfg(feature = "pyo3")]
 #[pymethods]
-impl StringChunkIter {
+impl PyStringChunkIter {
     #[must_use]
     const fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
         slf
     }
 
     #[must_use]
-    fn __next__(&mut self) -> Option<&'static str> {
-        self.next()
+    fn __next__(&mut self) -> Option<&str> {
+        self.words.get(self.remaining.next()?)
     }
 
     #[must_use]
-    pub const fn __len__(&self) -> usize {
-        self.remaining_words()
+    pub fn __len__(&self) -> usize {
+        self.remaining.len()
     }
 
     #[must_use]
-    #[allow(clippy::needless_pass_by_value)]
     pub fn __reversed__(
-        slf: PyRef<'_, Self>,
+        &self,
     ) -> super::reversed_string_chunk_iter::ReversedStringChunkIter {
-        super::reversed_string_chunk_iter::ReversedStringChunkIter::from(
-            slf.clone(),
-        )
+        super::reversed_string_chunk_iter::ReversedStringChunkIter {
+            words: self.words.clone(),
+            remaining: self.remaining.clone(),
+        }
     }
 }
 
@@ -182,7 +219,7 @@ mod tests {
 
         assert!(length > 100);
 
-        let iterator: StringChunkIter = sequence.into_iter();
+        let iterator: StringChunkIter = sequence.iter();
 
         assert_eq!(iterator.len(), length);
         assert_eq!(iterator.size_hint(), (length, Some(length)));
@@ -199,8 +236,7 @@ mod tests {
 
     #[test]
     fn test_string_chunk_iter_being_fused() {
-        let mut iterator: StringChunkIter =
-            Language::DeUmlauts.read_words(6).iter();
+        let mut iterator: StringChunkIter = Language::DeUmlauts.words(6);
 
         let start_length = iterator.len();
         assert!(start_length > 100);
@@ -222,8 +258,7 @@ mod tests {
 
     #[test]
     fn test_string_chunk_iter_being_double_ended() {
-        let mut iterator: StringChunkIter =
-            Language::DeUmlauts.read_words(10).iter();
+        let mut iterator: StringChunkIter = Language::DeUmlauts.words(10);
 
         let mut last_hundred_words = vec![];
 
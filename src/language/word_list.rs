@@ -0,0 +1,422 @@
+// SPDX-License-Identifier: EUPL-1.2
+use std::fs;
+use std::io;
+use std::num::NonZeroUsize;
+use std::ops::Range;
+use std::path::Path;
+use std::sync::Arc;
+
+use itertools::Itertools;
+
+#[cfg(feature = "wasm-bindgen")]
+use js_sys::JsString;
+#[cfg(feature = "pyo3")]
+use pyo3::prelude::*;
+#[cfg(feature = "wasm-bindgen")]
+use wasm_bindgen::prelude::*;
+
+#[cfg(feature = "wasm-bindgen")]
+use crate::solver::{ChunkedSolve, Pattern, WasmHangmanResult, WordSelection};
+
+use super::{Language, WordSequence};
+
+const RESERVED_CHARS: [char; 5] = ['#', '?', '_', '\0', '\n'];
+
+/// Trim and lowercase a word, like the words of the word lists.
+#[must_use]
+pub fn normalise_word(word: &str) -> String {
+    word.trim().to_lowercase()
+}
+
+/// The padded byte count of the words with a char count
+/// and their range in the data.
+type Bucket = (NonZeroUsize, Range<usize>);
+
+/// A custom list of words, stored like the words of a `Language`.
+///
+/// Clones share the words, they are freed with the last clone
+/// and the last `WordSequence` of the list.
+#[cfg_attr(feature = "pyo3", pyclass(frozen, from_py_object))]
+#[derive(Clone, Debug)]
+pub struct WordList {
+    /// The padded words, sorted by their char count.
+    data: Arc<str>,
+    /// The words by their char count.
+    buckets: Arc<[Bucket]>,
+}
+
+impl WordList {
+    /// Create a word list from words.
+    ///
+    /// The words are trimmed and lowercased, empty words and words with
+    /// whitespace or wildcards are ignored.
+    #[must_use]
+    pub fn new<S: AsRef<str>>(words: impl IntoIterator<Item = S>) -> Self {
+        let mut words: Vec<(usize, String)> = words
+            .into_iter()
+            .map(|word| normalise_word(word.as_ref()))
+            .filter(|word| {
+                !word.is_empty()
+                    && !word.contains(char::is_whitespace)
+                    && !word.contains(RESERVED_CHARS)
+            })
+            .map(|word| (word.chars().count(), word))
+            .collect();
+
+        words.sort_unstable();
+        words.dedup();
+
+        let mut data = String::new();
+        let mut buckets: Vec<Bucket> = Vec::new();
+        for (char_count, chunk) in &words.iter().chunk_by(|(length, _)| *length)
+        {
+            let chunk: Vec<&str> =
+                chunk.map(|(_, word)| word.as_str()).collect();
+            let padded_byte_count = chunk
+                .iter()
+                .map(|word| word.len())
+                .max()
+                .and_then(NonZeroUsize::new)
+                .unwrap_or(NonZeroUsize::MIN);
+
+            let start = data.len();
+            for word in chunk {
+                data.extend(std::iter::repeat_n(
+                    '\0',
+                    padded_byte_count.get() - word.len(),
+                ));
+                data.push_str(word);
+            }
+            buckets.resize(char_count, (NonZeroUsize::MIN, start..start));
+            buckets.push((padded_byte_count, start..data.len()));
+        }
+
+        Self {
+            data: data.into(),
+            buckets: buckets.into(),
+        }
+    }
+
+    /// Read a word list with a word per line.
+    pub fn from_file(path: impl AsRef<Path>) -> io::Result<Self> {
+        Ok(Self::new(fs::read_to_string(path)?.lines()))
+    }
+
+    /// The words with `length` chars.
+    #[must_use]
+    pub fn read_words(&self, length: usize) -> WordSequence {
+        let (padded_byte_count, range) = self
+            .buckets
+            .get(length)
+            .cloned()
+            .unwrap_or((NonZeroUsize::MIN, 0..0));
+
+        WordSequence::shared(
+            length,
+            Arc::clone(&self.data),
+            range,
+            padded_byte_count,
+        )
+    }
+
+    /// The length of the longest word.
+    #[must_use]
+    pub fn max_word_length(&self) -> usize {
+        self.buckets.len().saturating_sub(1)
+    }
+
+    /// The count of all words.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        (0..self.buckets.len())
+            .map(|length| self.read_words(length).len())
+            .sum()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.buckets.is_empty()
+    }
+}
+
+/// The words to solve a pattern with.
+#[derive(Clone, Debug)]
+pub enum WordSource {
+    Language(Language),
+    WordList(WordList),
+}
+
+impl WordSource {
+    /// The words with `length` chars.
+    #[must_use]
+    pub fn read_words(&self, length: usize) -> WordSequence {
+        match self {
+            Self::Language(language) => language.read_words(length),
+            Self::WordList(word_list) => word_list.read_words(length),
+        }
+    }
+
+    /// The length of the longest word.
+    #[must_use]
+    pub fn max_word_length(&self) -> usize {
+        match self {
+            Self::Language(language) => language.max_word_length(),
+            Self::WordList(word_list) => word_list.max_word_length(),
+        }
+    }
+
+    /// The language, `None` for a custom word list.
+    #[must_use]
+    pub const fn language(&self) -> Option<Language> {
+        match self {
+            Self::Language(language) => Some(*language),
+            Self::WordList(_) => None,
+        }
+    }
+}
+
+impl From<Language> for WordSource {
+    fn from(language: Language) -> Self {
+        Self::Language(language)
+    }
+}
+
+impl From<WordList> for WordSource {
+    fn from(word_list: WordList) -> Self {
+        Self::WordList(word_list)
+    }
+}
+
+#[cfg(feature = "pyo3")]
+impl<'a, 'py> FromPyObject<'a, 'py> for WordSource {
+    type Error = PyErr;
+
+    fn extract(obj: Borrowed<'a, 'py, PyAny>) -> Result<Self, Self::Error> {
+        if let Ok(language) = obj.extract::<Language>() {
+            return Ok(Self::Language(language));
+        }
+        obj.extract::<WordList>().map(Self::WordList).map_err(|_| {
+            pyo3::exceptions::PyTypeError::new_err(
+                "expected a Language or a WordList",
+            )
+        })
+    }
+}
+
+#[cfg(feature = "pyo3")]
+#[pymethods]
+impl WordList {
+    /// Create a word list from an iterable of words.
+    ///
+    /// The words are lowercased, words with whitespace or wildcards are ignored.
+    #[new]
+    fn py_new(py: Python<'_>, words: &Bound<'_, PyAny>) -> PyResult<Self> {
+        if words.is_instance_of::<pyo3::types::PyString>() {
+            return Err(pyo3::exceptions::PyTypeError::new_err(
+                "expected an iterable of words, not a string",
+            ));
+        }
+        let words: Vec<String> = words
+            .try_iter()?
+            .map(|word| word?.extract::<String>())
+            .collect::<PyResult<_>>()?;
+
+        Ok(py.detach(|| Self::new(words)))
+    }
+
+    /// Read a word list with a word per line.
+    #[staticmethod]
+    #[pyo3(name = "from_file")]
+    fn py_from_file(
+        py: Python<'_>,
+        path: std::path::PathBuf,
+    ) -> PyResult<Self> {
+        Ok(py.detach(|| Self::from_file(path))?)
+    }
+
+    /// Get all the words with the given length.
+    #[pyo3(name = "read_words")]
+    fn py_read_words(&self, word_length: usize) -> WordSequence {
+        self.read_words(word_length)
+    }
+
+    #[getter(max_word_length)]
+    fn py_max_word_length(&self) -> usize {
+        self.max_word_length()
+    }
+
+    fn __len__(&self) -> usize {
+        self.len()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "<WordList words={} max_word_length={}>",
+            self.len(),
+            self.max_word_length()
+        )
+    }
+}
+
+/// The words of a `WasmWordList`, the languages are only embedded
+/// with the `wasm-languages` feature.
+#[cfg(feature = "wasm-languages")]
+type WasmWords = WordSource;
+#[cfg(all(feature = "wasm-bindgen", not(feature = "wasm-languages")))]
+type WasmWords = WordList;
+
+/// A list of words to solve patterns with, created once and reused.
+///
+/// The words are freed with `free`, solvers created before keep them alive.
+#[cfg(feature = "wasm-bindgen")]
+#[wasm_bindgen(js_name = WordList)]
+pub struct WasmWordList {
+    words: WasmWords,
+}
+
+#[cfg(feature = "wasm-bindgen")]
+#[wasm_bindgen(js_class = WordList)]
+#[allow(clippy::useless_conversion)]
+impl WasmWordList {
+    /// Create a word list from an array of words.
+    #[wasm_bindgen(constructor)]
+    #[must_use]
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn new(words: Vec<JsString>) -> Self {
+        Self {
+            words: WordList::new(words.iter().map(String::from)).into(),
+        }
+    }
+
+    /// Create a word list from a string with a word per line.
+    #[wasm_bindgen(js_name = fromText)]
+    #[must_use]
+    pub fn from_text(text: &str) -> Self {
+        Self {
+            words: WordList::new(text.lines()).into(),
+        }
+    }
+
+    /// The embedded words of a language, like `de_umlauts`.
+    #[cfg(feature = "wasm-languages")]
+    #[wasm_bindgen(js_name = fromLanguage)]
+    pub fn from_language(name: &str) -> Result<Self, JsValue> {
+        Ok(Self {
+            words: Language::from_js_name(name)?.into(),
+        })
+    }
+
+    /// Solve a pattern with the words.
+    ///
+    /// The first `offset` matching words are skipped,
+    /// pass `next_offset` of the result to get the next words.
+    /// With a `seed` a random sample of the matching words is collected instead,
+    /// an `offset` can't be used with a `seed`.
+    pub fn solve(
+        &self,
+        pattern: &str,
+        invalid_letters: &str,
+        max_words_to_collect: usize,
+        offset: usize,
+        crossword_mode: bool,
+        seed: Option<u64>,
+    ) -> Result<WasmHangmanResult, JsValue> {
+        use unwrap_infallible::UnwrapInfallible as _;
+
+        let selection = WordSelection::from_js_args(offset, seed)?;
+        let pattern = Pattern::new(pattern, invalid_letters, !crossword_mode)
+            .unwrap_infallible();
+
+        Ok(pattern
+            .solve_sequence(
+                &self.words.read_words(pattern.pattern().len()),
+                WordSource::from(self.words.clone()).language(),
+                Some(max_words_to_collect),
+                selection,
+            )
+            .into())
+    }
+
+    /// Prepare solving a pattern a chunk of words at a time, like `solve`.
+    ///
+    /// Call `step` of the solver until it returns true, so a worker can
+    /// report the progress and stop early.
+    #[wasm_bindgen(js_name = solveChunked)]
+    pub fn solve_chunked(
+        &self,
+        pattern: &str,
+        invalid_letters: &str,
+        max_words_to_collect: usize,
+        offset: usize,
+        crossword_mode: bool,
+        seed: Option<u64>,
+    ) -> Result<ChunkedSolve, JsValue> {
+        use unwrap_infallible::UnwrapInfallible as _;
+
+        let selection = WordSelection::from_js_args(offset, seed)?;
+        let pattern = Pattern::new(pattern, invalid_letters, !crossword_mode)
+            .unwrap_infallible();
+        let words = self.words.read_words(pattern.pattern().len());
+
+        Ok(ChunkedSolve::new(
+            pattern,
+            words,
+            WordSource::from(self.words.clone()).language(),
+            Some(max_words_to_collect),
+            selection,
+        ))
+    }
+
+    /// The length of the longest word.
+    #[wasm_bindgen(getter = maxWordLength)]
+    #[must_use]
+    pub fn max_word_length(&self) -> usize {
+        self.words.max_word_length()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WordList;
+
+    #[test]
+    fn test_word_list() {
+        let words = WordList::new([
+            "Test",
+            "tee",
+            "täst",
+            "test",
+            " tost\n",
+            "te_t",
+            "two words",
+            "",
+            "a",
+        ]);
+
+        assert_eq!(words.len(), 5);
+        assert_eq!(words.max_word_length(), 4);
+        assert_eq!(
+            words.read_words(4).iter().collect::<Vec<_>>(),
+            ["test", "tost", "täst"]
+        );
+        assert_eq!(words.read_words(3).iter().collect::<Vec<_>>(), ["tee"]);
+        assert_eq!(words.read_words(1).iter().collect::<Vec<_>>(), ["a"]);
+        assert!(words.read_words(2).is_empty());
+        assert!(words.read_words(10).is_empty());
+        assert!(words.read_words(4).contains("täst"));
+        assert!(!words.read_words(4).contains("te_t"));
+
+        assert!(WordList::new([""; 0]).is_empty());
+    }
+
+    #[test]
+    fn test_word_sequence_of_dropped_word_list() {
+        let words =
+            WordList::new(["tent", "test", "text", "toast"]).read_words(4);
+
+        assert_eq!(words.iter().collect::<Vec<_>>(), ["tent", "test", "text"]);
+        assert_eq!(words.slice(1..3).get(1), Some("text"));
+        assert_eq!(words.slice(1..3).len(), 2);
+        assert_eq!(words.language_range(), None);
+    }
+}
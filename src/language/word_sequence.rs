@@ -1,6 +1,8 @@
 // SPDX-License-Identifier: EUPL-1.2
 use std::hash::{Hash, Hasher};
 use std::num::NonZeroUsize;
+use std::ops::Range;
+use std::sync::Arc;
 #[cfg(feature = "pyo3")]
 use std::sync::LazyLock;
 
@@ -14,23 +16,59 @@ use pyo3::{
     types::PySlice,
 };
 
-use super::StringChunkIter;
+use super::{Language, StringChunkIter};
 
-#[allow(dead_code)]
 const EMPTY_WORD_SEQUENCE: WordSequence = WordSequence {
     word_length: 0,
     padded_word_byte_count: NonZeroUsize::MIN,
-    data: "",
+    data: WordData::Static {
+        language: Language::En,
+        start: 0,
+        data: "",
+    },
 };
 
 const _: () = assert!(EMPTY_WORD_SEQUENCE.is_empty());
 const _: () = assert!(EMPTY_WORD_SEQUENCE.word_char_count() == 0);
 const _: () = assert!(EMPTY_WORD_SEQUENCE.is_empty());
 
-#[cfg_attr(feature = "pyo3", pyclass(frozen))]
+/// The padded words of a sequence.
+#[derive(Clone)]
+enum WordData {
+    /// The words of a language, embedded into the binary.
+    Static {
+        language: Language,
+        /// The index of the first word in the words of the language
+        start: usize,
+        data: &'static str,
+    },
+    /// The words of a word list, freed with the last sequence using them.
+    Shared(Arc<str>, Range<usize>),
+}
+
+impl WordData {
+    #[inline]
+    fn as_str(&self) -> &str {
+        match self {
+            Self::Static { data, .. } => data,
+            Self::Shared(data, range) => data.get(range.clone()).unwrap_or(""),
+        }
+    }
+
+    #[inline]
+    const fn len(&self) -> usize {
+        match self {
+            Self::Static { data, .. } => data.len(),
+            Self::Shared(_, range) => range.end - range.start,
+        }
+    }
+}
+
+#[cfg_attr(feature = "pyo3", pyclass(frozen, skip_from_py_object))]
+#[derive(Clone)]
 pub struct WordSequence {
     word_length: usize,
-    data: &'static str,
+    data: WordData,
     padded_word_byte_count: NonZeroUsize,
 }
 
@@ -48,13 +86,34 @@ impl WordSequence {
     #[inline]
     #[must_use]
     pub(crate) const fn new(
+        language: Language,
         word_length: usize,
         data: &'static str,
         padded_word_byte_count: NonZeroUsize,
     ) -> Self {
         Self {
             word_length,
-            data,
+            data: WordData::Static {
+                language,
+                start: 0,
+                data,
+            },
+            padded_word_byte_count,
+        }
+    }
+
+    /// The words of a word list in `range` of `data`.
+    #[inline]
+    #[must_use]
+    pub(crate) const fn shared(
+        word_length: usize,
+        data: Arc<str>,
+        range: Range<usize>,
+        padded_word_byte_count: NonZeroUsize,
+    ) -> Self {
+        Self {
+            word_length,
+            data: WordData::Shared(data, range),
             padded_word_byte_count,
         }
     }
@@ -74,13 +133,57 @@ impl WordSequence {
     #[inline]
     #[must_use]
     pub const fn is_empty(&self) -> bool {
-        self.data.is_empty()
+        self.data.len() == 0
     }
 
     #[inline]
     #[must_use]
     pub fn get(&self, index: usize) -> Option<&str> {
-        self.into_iter().nth(index)
+        self.iter().nth(index)
+    }
+
+    /// The words in `range`, a range of word indices.
+    #[cfg(any(feature = "pyo3", feature = "wasm-bindgen", test))]
+    #[must_use]
+    pub(crate) fn slice(&self, range: Range<usize>) -> Self {
+        let byte_count = self.padded_word_byte_count.get();
+        let end = range.end.min(self.len());
+        let start = range.start.min(end);
+        let bytes = start * byte_count..end * byte_count;
+
+        let data = match &self.data {
+            WordData::Static {
+                language,
+                start: first,
+                data,
+            } => WordData::Static {
+                language: *language,
+                start: first + start,
+                data: data.get(bytes).unwrap_or(""),
+            },
+            WordData::Shared(data, range) => WordData::Shared(
+                Arc::clone(data),
+                range.start + bytes.start..range.start + bytes.end,
+            ),
+        };
+
+        Self {
+            word_length: self.word_length,
+            data,
+            padded_word_byte_count: self.padded_word_byte_count,
+        }
+    }
+
+    /// The language of the words and the range of the words in the words
+    /// of the language with the same length, `None` for a word list.
+    #[must_use]
+    pub const fn language_range(&self) -> Option<(Language, Range<usize>)> {
+        match self.data {
+            WordData::Static {
+                language, start, ..
+            } => Some((language, start..start + self.len())),
+            WordData::Shared(..) => None,
+        }
     }
 
     #[inline]
@@ -109,7 +212,7 @@ impl WordSequence {
             match mid_value.cmp(word) {
                 std::cmp::Ordering::Less => low = mid + 1,
                 std::cmp::Ordering::Equal => return Some(mid),
-                std::cmp::Ordering::Greater => high = mid - 1,
+                std::cmp::Ordering::Greater => high = mid.checked_sub(1)?,
             }
         }
 
@@ -133,39 +236,38 @@ impl WordSequence {
 
     #[inline]
     #[must_use]
-    pub fn iter(&self) -> StringChunkIter {
-        self.into_iter()
+    pub fn iter(&self) -> StringChunkIter<'_> {
+        StringChunkIter::new(
+            self.word_length,
+            self.data.as_str(),
+            self.padded_word_byte_count,
+        )
     }
 
+    /// Iterate over the words of a language,
+    /// `None` for the words of a word list.
     #[inline]
-    const fn const_convert_to_iter(&self) -> StringChunkIter {
-        StringChunkIter {
-            is_ascii: self.word_length == self.padded_word_byte_count.get(),
-            padded_word_byte_count: self.padded_word_byte_count,
-            string: self.data,
+    #[must_use]
+    pub(crate) const fn static_iter(&self) -> Option<StringChunkIter<'static>> {
+        match self.data {
+            WordData::Static { data, .. } => Some(StringChunkIter::new(
+                self.word_length,
+                data,
+                self.padded_word_byte_count,
+            )),
+            WordData::Shared(..) => None,
         }
     }
 }
 
-impl IntoIterator for WordSequence {
-    type Item = &'static str;
-
-    type IntoIter = StringChunkIter;
-
-    #[inline]
-    fn into_iter(self) -> Self::IntoIter {
-        self.const_convert_to_iter()
-    }
-}
-
-impl IntoIterator for &WordSequence {
-    type Item = &'static str;
+impl<'a> IntoIterator for &'a WordSequence {
+    type Item = &'a str;
 
-    type IntoIter = StringChunkIter;
+    type IntoIter = StringChunkIter<'a>;
 
     #[inline]
     fn into_iter(self) -> Self::IntoIter {
-        self.const_convert_to_iter()
+        self.iter()
     }
 }
 
@@ -173,8 +275,8 @@ impl IntoIterator for &WordSequence {
 #[pymethods]
 impl WordSequence {
     #[must_use]
-    pub fn __iter__(&self) -> StringChunkIter {
-        self.into_iter()
+    pub fn __iter__(&self) -> super::string_chunk_iter::PyStringChunkIter {
+        super::string_chunk_iter::PyStringChunkIter::from(self.clone())
     }
 
     #[must_use]
@@ -199,6 +301,49 @@ impl WordSequence {
         py.detach(|| self.iter().zip_eq(other.iter()).all(|(a, b)| a == b))
     }
 
+    /// Pickle the sequence as the words of a language, sliced if needed.
+    ///
+    /// Sequences of custom word lists can't be pickled.
+    pub fn __reduce__<'py>(
+        &self,
+        py: Python<'py>,
+    ) -> PyResult<(Bound<'py, PyAny>, Bound<'py, pyo3::types::PyTuple>)> {
+        let (language, range) = if self.is_empty() {
+            (Language::all()[0], 0..0)
+        } else {
+            self.language_range().ok_or_else(|| {
+                PyTypeError::new_err(
+                    "words of custom word lists can't be pickled",
+                )
+            })?
+        };
+        let read_words_with_length = py
+            .import("hangman_solver")?
+            .getattr("read_words_with_length")?;
+        let words = language.read_words(self.word_length);
+
+        if range.len() == words.len() {
+            return Ok((
+                read_words_with_length,
+                (language, self.word_length).into_pyobject(py)?,
+            ));
+        }
+
+        Ok((
+            py.import("operator")?.getattr("getitem")?,
+            (
+                read_words_with_length.call1((language, self.word_length))?,
+                PySlice::new(
+                    py,
+                    range.start.try_into()?,
+                    range.end.try_into()?,
+                    1,
+                ),
+            )
+                .into_pyobject(py)?,
+        ))
+    }
+
     #[must_use]
     pub const fn __bool__(&self) -> bool {
         !self.is_empty()
@@ -270,25 +415,13 @@ impl WordSequence {
         }
         if let Ok(slice) = arg.cast::<PySlice>() {
             if self.is_empty() {
-                let value = Self {
-                    word_length: self.word_length,
-                    padded_word_byte_count: self.padded_word_byte_count,
-                    data: "",
-                };
-
-                return value.into_py_any(arg.py());
+                return self.slice(0..0).into_py_any(arg.py());
             }
 
             let indices = slice.indices(self.len().try_into()?)?;
 
             if indices.slicelength == 0 {
-                let value = Self {
-                    word_length: self.word_length,
-                    padded_word_byte_count: self.padded_word_byte_count,
-                    data: "",
-                };
-
-                return value.into_py_any(arg.py());
+                return self.slice(0..0).into_py_any(arg.py());
             }
 
             if indices.step == 1 {
@@ -301,16 +434,7 @@ impl WordSequence {
                     .try_into()
                     .expect("stop has to be positive if step is 1");
 
-                let range = start * self.padded_word_byte_count.get()
-                    ..stop * self.padded_word_byte_count.get();
-
-                let value = Self {
-                    word_length: self.word_length,
-                    padded_word_byte_count: self.padded_word_byte_count,
-                    data: self.data.get(range).unwrap_or(""),
-                };
-
-                return value.into_py_any(arg.py());
+                return self.slice(start..stop).into_py_any(arg.py());
             }
 
             let value: Vec<&str> = arg.py().detach(|| {
@@ -363,7 +487,7 @@ impl WordSequence {
         &self,
     ) -> super::reversed_string_chunk_iter::ReversedStringChunkIter {
         super::reversed_string_chunk_iter::ReversedStringChunkIter::from(
-            self.iter(),
+            self.clone(),
         )
     }
 
@@ -390,7 +514,7 @@ impl WordSequence {
             let mut iter = self.iter();
             while let Some(word) = iter.next() {
                 data.push_str(word);
-                if iter.__len__() > 0 {
+                if iter.len() > 0 {
                     data.push_str(SEPARATOR);
                 }
             }
@@ -422,7 +546,7 @@ mod tests {
             for i in 0..100 {
                 assert_eq!(
                     lang.read_words(i).len(),
-                    lang.read_words(i).into_iter().count()
+                    lang.read_words(i).iter().count()
                 );
             }
         }
@@ -434,7 +558,7 @@ mod tests {
             for i in 0..100 {
                 assert_eq!(lang.read_words(i).word_char_count(), i);
 
-                for word in lang.read_words(i) {
+                for word in &lang.read_words(i) {
                     assert_eq!(word.chars().count(), i);
                 }
             }
@@ -445,7 +569,7 @@ mod tests {
     fn test_word_sequence_is_sorted() {
         for lang in Language::all() {
             for i in 0..100 {
-                assert!(lang.read_words(i).into_iter().is_sorted());
+                assert!(lang.read_words(i).iter().is_sorted());
             }
         }
     }
@@ -458,7 +582,7 @@ mod tests {
                     .get(0)
                     .expect("we have more than 1 ten letter word"),
                 lang.read_words(10)
-                    .into_iter()
+                    .iter()
                     .next()
                     .expect("we have more than 1 ten letter word"),
             );
@@ -538,4 +662,26 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_word_sequence_slice() {
+        let words = Language::En.read_words(4);
+        let slice = words.slice(10..20);
+
+        assert_eq!(slice.len(), 10);
+        assert!(slice.iter().eq(words.iter().skip(10).take(10)));
+        assert!(slice.slice(2..4).iter().eq(words.iter().skip(12).take(2)));
+        assert!(words.slice(words.len()..words.len() + 5).is_empty());
+        assert_eq!(words.slice(10..usize::MAX).len(), words.len() - 10);
+
+        assert_eq!(
+            words.language_range(),
+            Some((Language::En, 0..words.len()))
+        );
+        assert_eq!(slice.language_range(), Some((Language::En, 10..20)));
+        assert_eq!(
+            slice.slice(2..4).language_range(),
+            Some((Language::En, 12..14))
+        );
+    }
 }
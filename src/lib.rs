@@ -8,17 +8,35 @@
 #![deny(clippy::indexing_slicing, clippy::panic, clippy::unwrap_used)]
 #![allow(clippy::missing_errors_doc, clippy::option_if_let_else)]
 #![deny(unsafe_code)]
+mod game;
 mod language;
+#[cfg(feature = "server")]
+pub mod server;
 mod solver;
 
 pub use crate::solver::{
-    CharCollection, HangmanResult, InfallibleCharCollection, Pattern,
+    CharCollection, HangmanResult, InfallibleCharCollection,
+    LetterFrequencyCounter, LetterStatistic, MatchesIter, Pattern,
+    ResultDisplay, ResultField, ResultFormat, TemplatePart, WordSelection,
+    parallel, solve_infallible,
 };
 
-pub use crate::language::{Language, StringChunkIter, WordSequence};
+pub use crate::language::{
+    Language, StringChunkIter, WordList, WordSequence, WordSource,
+    normalise_word,
+};
+
+pub use crate::game::{
+    Difficulty, DifficultyLevel, Guess, Host, Session, SessionError,
+    Simulation, WordPicker, simulate,
+};
 
+#[cfg(feature = "wasm-languages")]
+pub use crate::game::{GuessKind, WasmGuess};
+#[cfg(feature = "wasm-bindgen")]
+pub use crate::language::WasmWordList;
 #[cfg(feature = "wasm-bindgen")]
-pub use crate::solver::WasmHangmanResult;
+pub use crate::solver::{ChunkedSolve, WasmHangmanResult, WasmLetterCount};
 #[cfg(feature = "wasm-bindgen")]
 use js_sys::JsString;
 #[cfg(feature = "wasm-bindgen")]
@@ -27,6 +45,8 @@ use wasm_bindgen::prelude::*;
 #[cfg(feature = "pyo3")]
 pub use crate::language::UnknownLanguageError;
 #[cfg(feature = "pyo3")]
+use crate::solver::matches_iter::PyMatchesIter;
+#[cfg(feature = "pyo3")]
 use pyo3::exceptions::PyBaseExceptionGroup;
 #[cfg(feature = "pyo3")]
 use pyo3::prelude::*;
@@ -55,18 +75,25 @@ impl<'a, 'py> FromPyObject<'a, 'py> for InvalidLetters<'a> {
     }
 }
 
-/// Solve a pattern.
+/// Solve a pattern with the words of a language or a word list.
+///
+/// The first `offset` matching words are skipped,
+/// pass `next_offset` of the result to get the next words.
+/// With a `seed` a random sample of the matching words is collected instead.
 #[cfg(feature = "pyo3")]
 #[pyfunction]
-#[pyo3(signature = (pattern_string, invalid_letters, language, max_words_to_collect))]
+#[pyo3(signature = (pattern_string, invalid_letters, language, max_words_to_collect, offset = 0, seed = None))]
 #[allow(clippy::needless_pass_by_value)]
 pub fn solve(
     py: Python<'_>,
     pattern_string: std::borrow::Cow<'_, str>,
     invalid_letters: InvalidLetters<'_>,
-    language: Language,
+    language: WordSource,
     max_words_to_collect: usize,
-) -> HangmanResult {
+    offset: usize,
+    seed: Option<u64>,
+) -> PyResult<HangmanResult> {
+    let selection = WordSelection::from_py_args(offset, seed)?;
     let Result::<_, std::convert::Infallible>::Ok(result) =
         py.detach(|| match invalid_letters {
             InvalidLetters::String(invalid_letters) => crate::solver::solve(
@@ -75,6 +102,7 @@ pub fn solve(
                 true,
                 language,
                 Some(max_words_to_collect),
+                selection,
             ),
             InvalidLetters::Chars(invalid_letters) => crate::solver::solve(
                 &pattern_string,
@@ -82,26 +110,34 @@ pub fn solve(
                 true,
                 language,
                 Some(max_words_to_collect),
+                selection,
             ),
         });
 
-    result
+    Ok(result)
 }
 
-/// Solve a pattern.
+/// Solve a pattern with the words of a language or a word list.
 ///
 /// Crossword mode means that letters in the pattern can appear in other positions.
+///
+/// The first `offset` matching words are skipped,
+/// pass `next_offset` of the result to get the next words.
+/// With a `seed` a random sample of the matching words is collected instead.
 #[cfg(feature = "pyo3")]
 #[pyfunction]
-#[pyo3(signature = (pattern_string, invalid_letters, language, max_words_to_collect))]
+#[pyo3(signature = (pattern_string, invalid_letters, language, max_words_to_collect, offset = 0, seed = None))]
 #[allow(clippy::needless_pass_by_value)]
 pub fn solve_crossword(
     py: Python<'_>,
     pattern_string: std::borrow::Cow<'_, str>,
     invalid_letters: InvalidLetters<'_>,
-    language: Language,
+    language: WordSource,
     max_words_to_collect: usize,
-) -> HangmanResult {
+    offset: usize,
+    seed: Option<u64>,
+) -> PyResult<HangmanResult> {
+    let selection = WordSelection::from_py_args(offset, seed)?;
     let Result::<_, std::convert::Infallible>::Ok(result) =
         py.detach(|| match invalid_letters {
             InvalidLetters::String(invalid_letters) => crate::solver::solve(
@@ -110,6 +146,7 @@ pub fn solve_crossword(
                 false,
                 language,
                 Some(max_words_to_collect),
+                selection,
             ),
             InvalidLetters::Chars(invalid_letters) => crate::solver::solve(
                 &pattern_string,
@@ -117,10 +154,179 @@ pub fn solve_crossword(
                 false,
                 language,
                 Some(max_words_to_collect),
+                selection,
             ),
         });
 
-    result
+    Ok(result)
+}
+
+#[cfg(feature = "pyo3")]
+fn new_pattern(
+    pattern_string: &str,
+    invalid_letters: InvalidLetters<'_>,
+    crossword: bool,
+) -> Pattern {
+    use unwrap_infallible::UnwrapInfallible as _;
+
+    match invalid_letters {
+        InvalidLetters::String(invalid_letters) => {
+            Pattern::new(pattern_string, &invalid_letters, !crossword)
+        }
+        InvalidLetters::Chars(invalid_letters) => {
+            Pattern::new(pattern_string, &invalid_letters, !crossword)
+        }
+    }
+    .unwrap_infallible()
+}
+
+/// Solve a pattern in the default executor of the running event loop.
+///
+/// Returns the `asyncio.Future` of `loop.run_in_executor`,
+/// cancelling it stops the solve.
+/// Crossword mode (keyword only) means that letters in the pattern
+/// can appear in other positions.
+///
+/// The first `offset` matching words are skipped,
+/// pass `next_offset` of the result to get the next words.
+/// With a `seed` a random sample of the matching words is collected instead.
+#[cfg(feature = "pyo3")]
+#[pyfunction]
+#[pyo3(signature = (pattern_string, invalid_letters, language, max_words_to_collect, offset = 0, seed = None, *, crossword = false))]
+#[allow(clippy::needless_pass_by_value, clippy::too_many_arguments)]
+pub fn solve_async<'py>(
+    py: Python<'py>,
+    pattern_string: std::borrow::Cow<'_, str>,
+    invalid_letters: InvalidLetters<'_>,
+    language: WordSource,
+    max_words_to_collect: usize,
+    offset: usize,
+    seed: Option<u64>,
+    crossword: bool,
+) -> PyResult<Bound<'py, PyAny>> {
+    use pyo3::exceptions::asyncio::CancelledError;
+    use pyo3::types::PyCFunction;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    let selection = WordSelection::from_py_args(offset, seed)?;
+    let pattern = new_pattern(&pattern_string, invalid_letters, crossword);
+
+    let cancelled = Arc::new(AtomicBool::new(false));
+    let job = {
+        let cancelled = Arc::clone(&cancelled);
+        PyCFunction::new_closure(py, None, None, move |args, _| {
+            let py = args.py();
+            py.detach(|| {
+                pattern.solve_cancellable(
+                    language.clone(),
+                    Some(max_words_to_collect),
+                    selection,
+                    &cancelled,
+                )
+            })
+            .ok_or_else(|| CancelledError::new_err("the solve was cancelled"))
+        })?
+    };
+    let cancel = PyCFunction::new_closure(py, None, None, move |args, _| {
+        let future = args.get_item(0)?;
+        if future.call_method0("cancelled")?.is_truthy()? {
+            cancelled.store(true, Ordering::Relaxed);
+        }
+        PyResult::Ok(())
+    })?;
+
+    let future = py
+        .import("asyncio")?
+        .call_method0("get_running_loop")?
+        .call_method1("run_in_executor", (py.None(), job))?;
+    future.call_method1("add_done_callback", (cancel,))?;
+
+    Ok(future)
+}
+
+#[cfg(feature = "pyo3")]
+fn extract_pattern(
+    item: &Bound<'_, PyAny>,
+    crossword: bool,
+) -> PyResult<Pattern> {
+    if let Ok(pattern) = item.cast::<Pattern>() {
+        return Ok(pattern.get().clone());
+    }
+    let (pattern_string, invalid_letters) = match item
+        .extract::<std::borrow::Cow<'_, str>>()
+    {
+        Ok(pattern_string) => (pattern_string, InvalidLetters::Chars(vec![])),
+        Err(_) => item
+            .extract::<(std::borrow::Cow<'_, str>, InvalidLetters<'_>)>()
+            .map_err(|error| {
+                let type_error = pyo3::exceptions::PyTypeError::new_err(
+                    "expected a Pattern, a str or a tuple of a str and the invalid letters",
+                );
+                type_error.set_cause(item.py(), Some(error));
+                type_error
+            })?,
+    };
+
+    Ok(new_pattern(&pattern_string, invalid_letters, crossword))
+}
+
+/// Solve many patterns in parallel, the results are in the order of the patterns.
+///
+/// A pattern is a `Pattern`, a pattern string or a tuple of a pattern string
+/// and the invalid letters. Crossword mode is used for pattern strings,
+/// a `Pattern` keeps its mode.
+///
+/// The first `offset` matching words are skipped,
+/// with a `seed` a random sample of the matching words is collected instead.
+#[cfg(feature = "pyo3")]
+#[pyfunction]
+#[pyo3(signature = (patterns, language, max_words_to_collect, crossword = false, offset = 0, seed = None))]
+#[allow(clippy::needless_pass_by_value)]
+pub fn solve_many(
+    py: Python<'_>,
+    patterns: &Bound<'_, PyAny>,
+    language: WordSource,
+    max_words_to_collect: usize,
+    crossword: bool,
+    offset: usize,
+    seed: Option<u64>,
+) -> PyResult<Vec<HangmanResult>> {
+    let selection = WordSelection::from_py_args(offset, seed)?;
+    let patterns: Vec<Pattern> = patterns
+        .try_iter()?
+        .map(|item| extract_pattern(&item?, crossword))
+        .collect::<PyResult<_>>()?;
+
+    Ok(py.detach(|| {
+        crate::solver::parallel::par_map(&patterns, |pattern| {
+            pattern.solve(
+                language.clone(),
+                Some(max_words_to_collect),
+                selection,
+            )
+        })
+    }))
+}
+
+/// Lazily iterate over the words of a language or a word list matching a pattern.
+///
+/// Crossword mode means that letters in the pattern can appear in other positions.
+#[cfg(feature = "pyo3")]
+#[pyfunction]
+#[pyo3(signature = (pattern_string, invalid_letters, language, crossword = false))]
+#[allow(clippy::needless_pass_by_value)]
+#[must_use]
+pub fn matches_iter(
+    pattern_string: std::borrow::Cow<'_, str>,
+    invalid_letters: InvalidLetters<'_>,
+    language: WordSource,
+    crossword: bool,
+) -> PyMatchesIter {
+    let pattern = new_pattern(&pattern_string, invalid_letters, crossword);
+    let words = language.read_words(pattern.pattern().len());
+
+    PyMatchesIter::new(pattern, words)
 }
 
 /// Get all the words of a language with the given length.
@@ -141,16 +347,27 @@ pub const fn read_words_with_length(
 pub fn hangman_solver(py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(solve, m)?)?;
     m.add_function(wrap_pyfunction!(solve_crossword, m)?)?;
+    m.add_function(wrap_pyfunction!(solve_many, m)?)?;
+    m.add_function(wrap_pyfunction!(solve_async, m)?)?;
     m.add_function(wrap_pyfunction!(read_words_with_length, m)?)?;
+    m.add_function(wrap_pyfunction!(matches_iter, m)?)?;
     m.add(
         "UnknownLanguageError",
         py.get_type::<UnknownLanguageError>(),
     )?;
     m.add_class::<HangmanResult>()?;
+    m.add_class::<LetterStatistic>()?;
+    m.add_class::<PyMatchesIter>()?;
     m.add_class::<Language>()?;
+    m.add_class::<Session>()?;
+    m.add_class::<Pattern>()?;
+    m.add_class::<WordList>()?;
     Ok(())
 }
 
+/// Solve a pattern with the words.
+///
+/// `offset` and `seed` are optional and work like in `WordList.solve`.
 #[cfg(feature = "wasm-bindgen")]
 #[wasm_bindgen]
 #[allow(clippy::needless_pass_by_value)]
@@ -160,6 +377,8 @@ pub fn solve_hangman(
     invalid_letters: JsString,
     max_words_to_collect: usize,
     crossword_mode: bool,
+    offset: Option<usize>,
+    seed: Option<u64>,
 ) -> Result<WasmHangmanResult, JsValue> {
     use crate::solver::solve_js;
 
@@ -168,6 +387,7 @@ pub fn solve_hangman(
         &pattern_string,
         &invalid_letters,
         Some(max_words_to_collect),
+        WordSelection::from_js_args(offset.unwrap_or(0), seed)?,
         crossword_mode,
     ))
 }
@@ -8,93 +8,21 @@
 #![deny(clippy::indexing_slicing, clippy::panic, clippy::unwrap_used)]
 #![allow(clippy::missing_errors_doc, clippy::option_if_let_else)]
 #![deny(unsafe_code)]
-mod language;
-mod solver;
+mod cli;
 
 use std::env;
-use std::io::{self, BufRead};
 use std::process::exit;
 
-use itertools::Itertools;
-#[cfg(feature = "terminal_size")]
-use terminal_size::{Width, terminal_size};
-use unwrap_infallible::UnwrapInfallible;
-
-use crate::language::Language;
-use crate::solver::InfallibleCharCollection as _;
-use crate::solver::solve;
-
-fn get_terminal_width() -> usize {
-    #[cfg(feature = "terminal_size")]
-    if let Some((Width(w), _)) = terminal_size() {
-        return w.into();
-    }
-
-    80
-}
+#[cfg(test)]
+use hangman_solver_lib::{InfallibleCharCollection as _, Language};
 
 fn main() {
-    let args: Vec<String> = env::args().skip(1).collect();
-
-    if args.is_empty() {
-        eprintln!("Please set a language as argument.");
-        eprintln!(
-            "Valid languages: {}",
-            Language::all()
-                .iter()
-                .map(language::Language::name)
-                .join(", ")
-        );
+    let args = cli::Args::parse(env::args().skip(1)).unwrap_or_else(|error| {
+        eprintln!("{error}");
         exit(2);
-    } else if args.len() != 1 {
-        eprintln!("Too many arguments");
-        exit(1);
-    }
-
-    let lang = args
-        .first()
-        .and_then(|lang| Language::from_string(lang))
-        .unwrap_or_else(|| {
-            eprintln!("Invalid language");
-            exit(1);
-        });
-
-    let mut buffer = String::new();
-    let stdin = io::stdin();
+    });
 
-    loop {
-        let r = stdin.lock().read_line(&mut buffer);
-        match r {
-            Ok(result) => {
-                if buffer.is_empty() {
-                    exit(i32::from(result != 0));
-                }
-
-                let width = get_terminal_width();
-
-                let mut input =
-                    buffer.splitn(2, ' ').collect::<Box<[&str]>>().into_iter();
-                let pattern: &str = input.next().unwrap_or("");
-                let invalid: &str = input.next().unwrap_or("");
-                let hr = solve(
-                    pattern,
-                    invalid,
-                    true,
-                    lang,
-                    Some(width / pattern.char_count() + 1),
-                )
-                .unwrap_infallible();
-                assert_eq!(hr.language, lang);
-
-                println!("{hr:─^width$}");
-            }
-            Err(error) => {
-                eprintln!("{error}");
-            }
-        }
-
-        buffer.clear();
-    }
+    exit(cli::run(args));
 }
 
 #[test]
@@ -110,7 +38,7 @@ fn test_itering_words() -> Result<(), String> {
                 ));
             }
             let mut word_count = 0;
-            for word in lang.read_words(i) {
+            for word in &lang.read_words(i) {
                 if word.len() < i {
                     return Err(format!(
                         "{} < {} (word={word}, lang={lang:?})",
@@ -135,9 +63,9 @@ fn test_itering_words() -> Result<(), String> {
                         lang.read_words(i).__len__()
                     ));
                 }
-                let mut word_iterator = lang.read_words(i).into_iter();
+                let mut word_iterator = lang.words(i);
                 while word_iterator.next().is_some() {}
-                let len = word_iterator.__len__();
+                let len = word_iterator.len();
                 if len != 0 {
                     return Err(format!("__len__: {len} != 0 (lang={lang:?})"));
                 }
@@ -0,0 +1,282 @@
+// SPDX-License-Identifier: EUPL-1.2
+//! A small HTTP server exposing the solver as a JSON API.
+//!
+//! - `POST /solve` solves a pattern, the body is a JSON object like
+//!   `{"pattern": "_a__", "invalid": "e", "language": "en"}`
+//! - `GET /languages` lists the languages
+//! - `GET /words/{lang}/{len}?offset=0&limit=100` lists the words
+use std::error::Error;
+use std::io::Read;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use tiny_http::{Header, Method, Server};
+
+use crate::language::Language;
+use crate::solver::{WordSelection, solve_infallible};
+
+/// The most words returned by a single request.
+pub const MAX_WORDS: usize = 1000;
+/// The count of words returned if the request doesn't limit them.
+pub const DEFAULT_MAX_WORDS: usize = 100;
+/// The longest accepted request body in bytes.
+pub const MAX_BODY_SIZE: usize = 16 * 1024;
+/// The longest accepted pattern and invalid letters in chars.
+pub const MAX_PATTERN_LENGTH: usize = 128;
+
+/// A JSON response to a request.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    /// The serialized JSON
+    pub body: String,
+}
+
+impl Response {
+    fn ok(body: &impl Serialize) -> Self {
+        match serde_json::to_string(body) {
+            Ok(body) => Self { status: 200, body },
+            Err(error) => Self::error(500, error.to_string()),
+        }
+    }
+
+    fn error(status: u16, message: impl Into<String>) -> Self {
+        Self {
+            status,
+            body: json!({ "error": message.into() }).to_string(),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SolveRequest {
+    pattern: String,
+    #[serde(default)]
+    invalid: String,
+    language: Language,
+    #[serde(default)]
+    crossword: bool,
+    max_words: Option<usize>,
+    #[serde(default)]
+    offset: usize,
+    seed: Option<u64>,
+}
+
+fn solve(body: &[u8]) -> Response {
+    let request: SolveRequest = match serde_json::from_slice(body) {
+        Ok(request) => request,
+        Err(error) => return Response::error(400, error.to_string()),
+    };
+
+    if request.pattern.chars().count() > MAX_PATTERN_LENGTH
+        || request.invalid.chars().count() > MAX_PATTERN_LENGTH
+    {
+        return Response::error(400, "pattern or invalid letters too long");
+    }
+    if request.seed.is_some() && request.offset != 0 {
+        return Response::error(400, "offset can't be used with seed");
+    }
+
+    let result = solve_infallible(
+        request.pattern.as_str(),
+        request.invalid.as_str(),
+        !request.crossword,
+        request.language,
+        Some(
+            request
+                .max_words
+                .unwrap_or(DEFAULT_MAX_WORDS)
+                .min(MAX_WORDS),
+        ),
+        WordSelection::new(request.offset, request.seed),
+    );
+
+    Response::ok(&result)
+}
+
+fn languages() -> Response {
+    let languages: Vec<Value> = Language::all()
+        .iter()
+        .map(|language| {
+            json!({
+                "name": language.name(),
+                "max_word_length": language.max_word_length(),
+            })
+        })
+        .collect();
+
+    Response::ok(&languages)
+}
+
+fn query_value<'a>(query: &'a str, name: &str) -> Option<&'a str> {
+    query
+        .split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .find_map(|(key, value)| (key == name).then_some(value))
+}
+
+fn words(language: &str, length: &str, query: &str) -> Response {
+    let Some(language) = Language::from_string(language) else {
+        return Response::error(404, "unknown language");
+    };
+    let Ok(length) = length.parse::<usize>() else {
+        return Response::error(400, "invalid word length");
+    };
+    let (Ok(offset), Ok(limit)) = (
+        query_value(query, "offset").map_or(Ok(0), str::parse::<usize>),
+        query_value(query, "limit")
+            .map_or(Ok(DEFAULT_MAX_WORDS), str::parse::<usize>),
+    ) else {
+        return Response::error(400, "invalid offset or limit");
+    };
+
+    let words = language.read_words(length);
+    let count = words.len();
+    let page: Vec<&str> = words
+        .iter()
+        .skip(offset)
+        .take(limit.min(MAX_WORDS))
+        .collect();
+    let next_offset = offset.saturating_add(page.len());
+
+    Response::ok(&json!({
+        "language": language,
+        "length": length,
+        "count": count,
+        "words": page,
+        "next_offset": (next_offset < count).then_some(next_offset),
+    }))
+}
+
+/// Handle a request, the url is the path with the optional query.
+#[must_use]
+pub fn handle(method: &str, url: &str, body: &[u8]) -> Response {
+    let (path, query) = url.split_once('?').unwrap_or((url, ""));
+    let segments: Vec<&str> = path
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+        .collect();
+
+    match (method, segments.as_slice()) {
+        ("POST", ["solve"]) => solve(body),
+        ("GET", ["languages"]) => languages(),
+        ("GET", ["words", language, length]) => words(language, length, query),
+        (_, ["solve" | "languages"] | ["words", _, _]) => {
+            Response::error(405, "method not allowed")
+        }
+        _ => Response::error(404, "not found"),
+    }
+}
+
+/// Serve the API on the address until the process is stopped.
+pub fn serve(address: &str) -> Result<(), Box<dyn Error + Send + Sync>> {
+    let server = Server::http(address)?;
+    let content_type = Header::from_bytes("Content-Type", "application/json")
+        .map_err(|()| "invalid header")?;
+
+    for mut request in server.incoming_requests() {
+        let mut body = Vec::new();
+        let too_large = request
+            .body_length()
+            .is_some_and(|length| length > MAX_BODY_SIZE)
+            || request
+                .as_reader()
+                .take(u64::try_from(MAX_BODY_SIZE).unwrap_or(u64::MAX) + 1)
+                .read_to_end(&mut body)
+                .is_ok_and(|length| length > MAX_BODY_SIZE);
+
+        let response = if too_large {
+            Response::error(413, "request body too large")
+        } else {
+            let method = match request.method() {
+                Method::Get => "GET",
+                Method::Post => "POST",
+                _ => "",
+            };
+            handle(method, request.url(), &body)
+        };
+
+        let response = tiny_http::Response::from_string(response.body)
+            .with_status_code(response.status)
+            .with_header(content_type.clone());
+        if let Err(error) = request.respond(response) {
+            eprintln!("{error}");
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{Value, json};
+
+    use super::{MAX_WORDS, handle};
+
+    fn body(response: &super::Response) -> Value {
+        serde_json::from_str(&response.body).expect("valid json")
+    }
+
+    #[test]
+    fn test_solve() {
+        let response = handle(
+            "POST",
+            "/solve",
+            br#"{"pattern": "te_t", "language": "en", "max_words": 1}"#,
+        );
+        assert_eq!(response.status, 200);
+        assert_eq!(body(&response).pointer("/count"), Some(&json!(4)));
+        assert_eq!(body(&response).pointer("/words"), Some(&json!(["teat"])));
+        assert_eq!(body(&response).pointer("/next_offset"), Some(&json!(1)));
+        assert!(
+            response
+                .body
+                .contains(r#""letter_frequency":{"a":1,"n":1,"s":1,"x":1}"#)
+        );
+
+        let response = handle(
+            "POST",
+            "/solve",
+            br#"{"pattern": "_____", "language": "en", "max_words": 99999}"#,
+        );
+        assert_eq!(
+            body(&response)
+                .pointer("/words")
+                .and_then(|words| words.as_array())
+                .map(Vec::len),
+            Some(MAX_WORDS)
+        );
+
+        for request in [
+            &br#"{"pattern": "te_t", "language": "xx"}"#[..],
+            br#"{"pattern": "te_t"}"#,
+            br#"{"pattern": "te_t", "language": "en", "x": 1}"#,
+            br#"{"pattern": "te_t", "language": "en", "seed": 1, "offset": 1}"#,
+            b"not json",
+        ] {
+            assert_eq!(handle("POST", "/solve", request).status, 400);
+        }
+        assert_eq!(handle("GET", "/solve", b"").status, 405);
+    }
+
+    #[test]
+    fn test_languages_and_words() {
+        let response = handle("GET", "/languages", b"");
+        assert_eq!(response.status, 200);
+        assert_eq!(body(&response).pointer("/4/name"), Some(&json!("en")));
+
+        let response = handle("GET", "/words/en/2?offset=1&limit=2", b"");
+        assert_eq!(response.status, 200);
+        assert_eq!(
+            body(&response).pointer("/words"),
+            Some(&json!(["ac", "ad"]))
+        );
+        assert_eq!(body(&response).pointer("/next_offset"), Some(&json!(3)));
+
+        assert_eq!(handle("GET", "/words/xx/2", b"").status, 404);
+        assert_eq!(handle("GET", "/words/en/x", b"").status, 400);
+        assert_eq!(handle("GET", "/words/en/2?limit=-1", b"").status, 400);
+        assert_eq!(handle("GET", "/unknown", b"").status, 404);
+    }
+}
@@ -0,0 +1,273 @@
+// SPDX-License-Identifier: EUPL-1.2
+use std::borrow::Cow;
+
+#[cfg(feature = "wasm-bindgen")]
+use wasm_bindgen::prelude::*;
+
+use crate::language::{Language, WordSequence};
+use crate::solver::hangman_result::HangmanResult;
+#[cfg(feature = "wasm-bindgen")]
+use crate::solver::hangman_result::WasmHangmanResult;
+use crate::solver::letter_frequency::LetterFrequencyCounter;
+use crate::solver::pattern::Pattern;
+use crate::solver::random::Reservoir;
+use crate::solver::word_selection::WordSelection;
+
+/// The words collected so far.
+#[derive(Clone, Debug)]
+enum Collected {
+    Words(Vec<Cow<'static, str>>),
+    Sample(Reservoir<Cow<'static, str>>),
+}
+
+/// Solves a pattern a chunk of words at a time.
+///
+/// Call `step` until it returns true, the result of every step is the
+/// result of the words processed so far. The final result is the same as
+/// the result of `Pattern::solve_sequence`.
+#[cfg_attr(feature = "wasm-bindgen", wasm_bindgen(js_name = ChunkedSolver))]
+#[derive(Clone)]
+pub struct ChunkedSolve {
+    pattern: Pattern,
+    words: WordSequence,
+    /// The count of the words processed so far
+    position: usize,
+    language: Option<Language>,
+    max_words_to_collect: Option<usize>,
+    selection: WordSelection,
+    letter_counter: LetterFrequencyCounter,
+    collected: Collected,
+}
+
+impl ChunkedSolve {
+    /// Prepare solving the pattern with words with the length of the pattern.
+    #[must_use]
+    pub fn new(
+        pattern: Pattern,
+        words: WordSequence,
+        language: Option<Language>,
+        max_words_to_collect: Option<usize>,
+        selection: WordSelection,
+    ) -> Self {
+        let collected = match (selection, max_words_to_collect) {
+            (WordSelection::Sample { seed }, Some(n)) => {
+                Collected::Sample(Reservoir::new(n, seed))
+            }
+            _ => Collected::Words(Vec::new()),
+        };
+
+        Self {
+            letter_counter: pattern.letter_frequency_counter(),
+            words,
+            position: 0,
+            pattern,
+            language,
+            max_words_to_collect,
+            selection,
+            collected,
+        }
+    }
+
+    /// Process the next `chunk_size` words, returns true once all words
+    /// have been processed.
+    #[expect(clippy::used_underscore_items)]
+    pub fn step(&mut self, chunk_size: usize) -> bool {
+        let end = self
+            .position
+            .saturating_add(chunk_size.max(1))
+            .min(self.total());
+        let chunk = self.words.slice(self.position..end);
+        self.position = end;
+
+        let letter_counter = match &mut self.collected {
+            Collected::Words(collected) => {
+                let selection = match self.selection {
+                    WordSelection::First { offset } => WordSelection::First {
+                        offset: offset.saturating_sub(
+                            usize::try_from(self.letter_counter.words_count())
+                                .unwrap_or(usize::MAX),
+                        ),
+                    },
+                    WordSelection::Sample { .. } => WordSelection::default(),
+                };
+                let max_words_to_collect = self
+                    .max_words_to_collect
+                    .map(|max| max.saturating_sub(collected.len()));
+
+                let (words, letter_counter) = self.pattern._solve_sequence(
+                    &chunk,
+                    max_words_to_collect,
+                    selection,
+                    || true,
+                );
+                collected.extend(words);
+                letter_counter
+            }
+            Collected::Sample(reservoir) => {
+                let (words, letter_counter) = self.pattern._solve_sequence(
+                    &chunk,
+                    None,
+                    WordSelection::default(),
+                    || true,
+                );
+                reservoir.extend(words);
+                letter_counter
+            }
+        };
+        self.letter_counter.merge(&letter_counter);
+
+        self.is_done()
+    }
+
+    /// The count of the words processed so far.
+    #[must_use]
+    pub const fn processed(&self) -> usize {
+        self.position
+    }
+
+    /// The count of all words to process.
+    #[must_use]
+    pub const fn total(&self) -> usize {
+        self.words.len()
+    }
+
+    /// The share of the words processed so far, from 0 to 1.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn progress(&self) -> f64 {
+        if self.total() == 0 {
+            return 1.0;
+        }
+        self.processed() as f64 / self.total() as f64
+    }
+
+    #[must_use]
+    pub const fn is_done(&self) -> bool {
+        self.position == self.total()
+    }
+
+    /// The result of the words processed so far.
+    pub fn result(&self) -> HangmanResult {
+        let possible_words = match &self.collected {
+            Collected::Words(words) => words.clone(),
+            Collected::Sample(reservoir) => reservoir.to_vec(),
+        };
+
+        self.pattern.result(
+            self.language,
+            self.selection,
+            possible_words,
+            &self.letter_counter,
+        )
+    }
+}
+
+#[cfg(feature = "wasm-bindgen")]
+#[wasm_bindgen(js_class = ChunkedSolver)]
+impl ChunkedSolve {
+    /// Process the next `chunkSize` words, returns true once all words
+    /// have been processed.
+    ///
+    /// To cancel the solve stop calling `step` and `free` the solver.
+    #[wasm_bindgen(js_name = step)]
+    pub fn js_step(&mut self, chunk_size: usize) -> bool {
+        self.step(chunk_size)
+    }
+
+    #[wasm_bindgen(getter = processed)]
+    #[must_use]
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn js_processed(&self) -> usize {
+        self.processed()
+    }
+
+    #[wasm_bindgen(getter = total)]
+    #[must_use]
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn js_total(&self) -> usize {
+        self.total()
+    }
+
+    /// The share of the words processed so far, from 0 to 1.
+    #[wasm_bindgen(getter = progress)]
+    #[must_use]
+    pub fn js_progress(&self) -> f64 {
+        self.progress()
+    }
+
+    #[wasm_bindgen(getter = isDone)]
+    #[must_use]
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn js_is_done(&self) -> bool {
+        self.is_done()
+    }
+
+    /// The result of the words processed so far.
+    #[wasm_bindgen(js_name = result)]
+    #[must_use]
+    pub fn js_result(&self) -> WasmHangmanResult {
+        self.result().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use unwrap_infallible::UnwrapInfallible;
+
+    use super::ChunkedSolve;
+    use crate::Language;
+    use crate::solver::{Pattern, WordSelection};
+
+    #[test]
+    fn test_chunked_solve() {
+        for (pattern, invalid_letters, crossword) in [
+            ("_____", "e", false),
+            ("__r_el_ier", "ix", false),
+            ("t__t", "", true),
+            ("#a__", "s", true),
+        ] {
+            let pattern = Pattern::new(pattern, invalid_letters, !crossword)
+                .unwrap_infallible();
+            let words = || Language::En.read_words(pattern.pattern().len());
+
+            for (max_words_to_collect, selection) in [
+                (None, WordSelection::default()),
+                (Some(3), WordSelection::default()),
+                (Some(5), WordSelection::First { offset: 4 }),
+                (None, WordSelection::First { offset: 2 }),
+                (Some(10), WordSelection::Sample { seed: 1 }),
+                (None, WordSelection::Sample { seed: 2 }),
+            ] {
+                let expected = pattern.solve_sequence(
+                    &words(),
+                    Some(Language::En),
+                    max_words_to_collect,
+                    selection,
+                );
+
+                for chunk_size in [0, 1, 7, 1000, usize::MAX] {
+                    let mut solve = ChunkedSolve::new(
+                        pattern.clone(),
+                        words(),
+                        Some(Language::En),
+                        max_words_to_collect,
+                        selection,
+                    );
+                    assert_eq!(solve.total(), words().len());
+
+                    let mut processed = 0;
+                    while !solve.step(chunk_size) {
+                        assert!(solve.processed() > processed);
+                        assert!(solve.progress() < 1.0);
+                        processed = solve.processed();
+                    }
+
+                    assert!(solve.is_done());
+                    assert_eq!(solve.processed(), words().len());
+                    assert!((solve.progress() - 1.0).abs() < f64::EPSILON);
+                    assert!(solve.result() == expected);
+                }
+            }
+        }
+    }
+}
@@ -1,8 +1,10 @@
 // SPDX-License-Identifier: EUPL-1.2
 
+use std::borrow::Cow;
 use std::fmt::Display;
 
 use crate::Language;
+use crate::solver::LetterStatistic;
 use crate::solver::infallible_char_collection::InfallibleCharCollection as _;
 
 #[cfg(feature = "pyo3")]
@@ -42,7 +44,8 @@ fn join_with_max_length<T: ExactSizeIterator<Item = String>>(
 cfg_select! {
     feature = "pyo3" => {
         /// The result of a hangman solve.
-        #[pyclass]
+        #[pyclass(eq, frozen, module = "hangman_solver")]
+        #[derive(PartialEq)]
         #[must_use]
         pub struct HangmanResult {
             /// The input string
@@ -58,13 +61,19 @@ cfg_select! {
             ///
             /// Compare to `matching_words_count` to see if these are all.
             #[pyo3(get, name = "words")]
-            pub possible_words: Vec<&'static str>,
-            /// The language used.
+            pub possible_words: Vec<Cow<'static, str>>,
+            /// The offset to get the next words with, if there are more.
             #[pyo3(get)]
-            pub language: Language,
+            pub next_offset: Option<usize>,
+            /// The language used, `None` for a custom word list.
+            #[pyo3(get)]
+            pub language: Option<Language>,
             /// Letter frequence of all possible words in unsolved positions.
             #[pyo3(get)]
             pub letter_frequency: Vec<(char, u32)>,
+            /// Statistics of the letters in `letter_frequency`, in the same order.
+            #[pyo3(get)]
+            pub letter_statistics: Vec<LetterStatistic>,
         }
 
         #[pymethods]
@@ -72,7 +81,7 @@ cfg_select! {
             fn __repr__(&self) -> String {
                 let id: *const Self = std::ptr::from_ref::<Self>(self);
                 let count = self.matching_words_count;
-                let lang = self.language.name();
+                let lang = self.language.as_ref().map_or("custom", Language::name);
                 let pattern = &self.input;
                 let invalid = &self.invalid;
 
@@ -87,17 +96,42 @@ cfg_select! {
                     format!("<HangmanResult lang={lang} pattern={pattern} invalid={invalid:?} count={count} at {id:?}>")
                 }
             }
+
+            fn __hash__(&self) -> u64 {
+                py_hash(self)
+            }
+
+            /// Convert the result to a dict, like the JSON output of the CLI.
+            fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, pyo3::types::PyDict>> {
+                to_py_dict(self, py)
+            }
+
+            /// Create a result from a dict returned by `to_dict`.
+            ///
+            /// The words have to be words of the language,
+            /// the words of custom word lists are restored as they are.
+            #[staticmethod]
+            fn from_dict(dict: &Bound<'_, pyo3::types::PyDict>) -> PyResult<Self> {
+                from_py_dict(dict)
+            }
+
+            fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyAny>, (Bound<'py, pyo3::types::PyDict>,))> {
+                Ok((slf.get_type().getattr("from_dict")?, (slf.get().to_dict(slf.py())?,)))
+            }
         }
     }
     _ => {
+        #[derive(PartialEq)]
         #[must_use]
         pub struct HangmanResult {
             pub input: String,
             pub invalid: Vec<char>,
             pub matching_words_count: u32,
-            pub possible_words: Vec<&'static str>,
-            pub language: Language,
+            pub possible_words: Vec<Cow<'static, str>>,
+            pub next_offset: Option<usize>,
+            pub language: Option<Language>,
             pub letter_frequency: Vec<(char, u32)>,
+            pub letter_statistics: Vec<LetterStatistic>,
         }
     }
 }
@@ -119,7 +153,7 @@ impl std::fmt::Display for HangmanResult {
             file,
             " words:   {}",
             join_with_max_length(
-                self.possible_words.iter().map(|word| String::from(*word)),
+                self.possible_words.iter().map(ToString::to_string),
                 ", ",
                 max_line_length - " words:   ".len(),
             )
@@ -143,17 +177,249 @@ impl std::fmt::Display for HangmanResult {
     }
 }
 
+#[cfg(feature = "pyo3")]
+fn py_hash(result: &HangmanResult) -> u64 {
+    use std::hash::{BuildHasher as _, RandomState};
+    use std::sync::LazyLock;
+
+    static RANDOM: LazyLock<RandomState> = LazyLock::new(RandomState::new);
+
+    RANDOM.hash_one((
+        &result.input,
+        &result.invalid,
+        result.matching_words_count,
+        &result.possible_words,
+        result.next_offset,
+        result.language.as_ref().map(Language::name),
+        &result.letter_frequency,
+    ))
+}
+
+#[cfg(feature = "pyo3")]
+fn to_py_dict<'py>(
+    result: &HangmanResult,
+    py: Python<'py>,
+) -> PyResult<Bound<'py, pyo3::types::PyDict>> {
+    use pyo3::types::PyDict;
+
+    let letter_frequency = PyDict::new(py);
+    for (letter, count) in &result.letter_frequency {
+        letter_frequency.set_item(letter, count)?;
+    }
+    let letter_statistics = result
+        .letter_statistics
+        .iter()
+        .map(|statistic| {
+            let dict = PyDict::new(py);
+            dict.set_item("letter", statistic.letter)?;
+            dict.set_item("probability", statistic.probability)?;
+            dict.set_item("expected_reveals", statistic.expected_reveals)?;
+            dict.set_item("miss_probability", statistic.miss_probability)?;
+            Ok(dict)
+        })
+        .collect::<PyResult<Vec<_>>>()?;
+
+    let dict = PyDict::new(py);
+    dict.set_item("input", &result.input)?;
+    dict.set_item("invalid", result.invalid.iter().collect::<String>())?;
+    dict.set_item("count", result.matching_words_count)?;
+    dict.set_item("words", &result.possible_words)?;
+    dict.set_item("next_offset", result.next_offset)?;
+    dict.set_item("letter_frequency", letter_frequency)?;
+    dict.set_item("letter_statistics", letter_statistics)?;
+    dict.set_item("language", result.language.as_ref().map(Language::name))?;
+    Ok(dict)
+}
+
+/// Get a value of a dict, a `KeyError` is raised if it is missing.
+#[cfg(feature = "pyo3")]
+fn dict_item<'py, T>(
+    dict: &Bound<'py, pyo3::types::PyDict>,
+    key: &str,
+) -> PyResult<T>
+where
+    T: pyo3::conversion::FromPyObjectOwned<'py>,
+    for<'a> <T as FromPyObject<'a, 'py>>::Error: Into<PyErr>,
+{
+    optional_dict_item(dict, key)?
+        .ok_or_else(|| pyo3::exceptions::PyKeyError::new_err(key.to_owned()))
+}
+
+/// Get a value of a dict, `None` if it is missing or `None`.
+#[cfg(feature = "pyo3")]
+fn optional_dict_item<'py, T>(
+    dict: &Bound<'py, pyo3::types::PyDict>,
+    key: &str,
+) -> PyResult<Option<T>>
+where
+    T: pyo3::conversion::FromPyObjectOwned<'py>,
+    for<'a> <T as FromPyObject<'a, 'py>>::Error: Into<PyErr>,
+{
+    use pyo3::types::PyDictMethods as _;
+
+    dict.get_item(key)?
+        .filter(|value| !value.is_none())
+        .map(|value| value.extract().map_err(Into::into))
+        .transpose()
+}
+
+#[cfg(feature = "pyo3")]
+fn from_py_dict(
+    dict: &Bound<'_, pyo3::types::PyDict>,
+) -> PyResult<HangmanResult> {
+    use pyo3::exceptions::PyValueError;
+    use pyo3::types::PyDict;
+
+    let language = optional_dict_item::<String>(dict, "language")?
+        .map(|name| Language::parse_string(&name, None))
+        .transpose()?;
+    let words: Vec<String> = dict_item(dict, "words")?;
+    let possible_words = words
+        .into_iter()
+        .map(|word| {
+            if let Some(language) = language
+                && !language.read_words(word.chars().count()).contains(&word)
+            {
+                return Err(PyValueError::new_err(format!(
+                    "{word} is not a word of {}",
+                    language.name()
+                )));
+            }
+            Ok(Cow::Owned(word))
+        })
+        .collect::<PyResult<_>>()?;
+
+    let letter_frequency =
+        dict_item::<Bound<'_, PyDict>>(dict, "letter_frequency")?
+            .iter()
+            .map(|(letter, count)| Ok((letter.extract()?, count.extract()?)))
+            .collect::<PyResult<_>>()?;
+    let letter_statistics = optional_dict_item::<Vec<Bound<'_, PyDict>>>(
+        dict,
+        "letter_statistics",
+    )?
+    .unwrap_or_default()
+    .iter()
+    .map(|statistic| {
+        Ok(LetterStatistic {
+            letter: dict_item(statistic, "letter")?,
+            probability: dict_item(statistic, "probability")?,
+            expected_reveals: dict_item(statistic, "expected_reveals")?,
+            miss_probability: dict_item(statistic, "miss_probability")?,
+        })
+    })
+    .collect::<PyResult<_>>()?;
+
+    Ok(HangmanResult {
+        input: dict_item(dict, "input")?,
+        matching_words_count: dict_item(dict, "count")?,
+        invalid: optional_dict_item::<String>(dict, "invalid")?
+            .unwrap_or_default()
+            .chars()
+            .collect(),
+        possible_words,
+        next_offset: optional_dict_item(dict, "next_offset")?,
+        language,
+        letter_frequency,
+        letter_statistics,
+    })
+}
+
+/// Serializes letter frequencies as an object, keeping their order.
+#[cfg(feature = "serde")]
+struct LetterFrequencyMap<'a>(&'a [(char, u32)]);
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for LetterFrequencyMap<'_> {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(self.0.iter().map(|(ch, count)| (ch, count)))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for HangmanResult {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("HangmanResult", 8)?;
+        state.serialize_field("input", &self.input)?;
+        state.serialize_field(
+            "invalid",
+            &self.invalid.iter().collect::<String>(),
+        )?;
+        state.serialize_field("count", &self.matching_words_count)?;
+        state.serialize_field("words", &self.possible_words)?;
+        state.serialize_field("next_offset", &self.next_offset)?;
+        state.serialize_field(
+            "letter_frequency",
+            &LetterFrequencyMap(&self.letter_frequency),
+        )?;
+        state.serialize_field("letter_statistics", &self.letter_statistics)?;
+        state.serialize_field("language", &self.language)?;
+        state.end()
+    }
+}
+
+/// A letter of the letter frequency of a `WasmHangmanResult`.
+#[cfg(feature = "wasm-bindgen")]
+#[wasm_bindgen(js_name = LetterCount)]
+#[derive(Copy, Clone, Debug)]
+pub struct WasmLetterCount {
+    #[wasm_bindgen(readonly)]
+    pub letter: char,
+    #[wasm_bindgen(readonly)]
+    pub count: u32,
+}
+
+/// The result of a hangman solve for JavaScript, see `HangmanResult`.
 #[cfg(feature = "wasm-bindgen")]
 #[wasm_bindgen(getter_with_clone)]
 pub struct WasmHangmanResult {
     #[wasm_bindgen(readonly)]
     pub input: JsString,
     #[wasm_bindgen(readonly)]
-    pub invalid: JsString,
+    pub invalid: Vec<JsString>,
     #[wasm_bindgen(readonly)]
     pub matching_words_count: u32,
     #[wasm_bindgen(readonly)]
     pub possible_words: Vec<JsString>,
     #[wasm_bindgen(readonly)]
-    pub letter_frequency: JsString,
+    pub next_offset: Option<usize>,
+    /// The letters by their frequency, the most frequent first.
+    #[wasm_bindgen(readonly)]
+    pub letter_frequency: Vec<WasmLetterCount>,
+    /// The name of the language, `undefined` for a custom word list.
+    #[wasm_bindgen(readonly)]
+    pub language: Option<JsString>,
+}
+
+#[cfg(feature = "wasm-bindgen")]
+impl From<HangmanResult> for WasmHangmanResult {
+    fn from(result: HangmanResult) -> Self {
+        Self {
+            input: JsString::from(result.input),
+            invalid: result.invalid.into_iter().map(JsString::from).collect(),
+            matching_words_count: result.matching_words_count,
+            possible_words: result
+                .possible_words
+                .iter()
+                .map(|word| JsString::from(word.as_ref()))
+                .collect(),
+            next_offset: result.next_offset,
+            letter_frequency: result
+                .letter_frequency
+                .into_iter()
+                .map(|(letter, count)| WasmLetterCount { letter, count })
+                .collect(),
+            language: result
+                .language
+                .map(|language| JsString::from(language.name())),
+        }
+    }
 }
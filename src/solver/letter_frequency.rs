@@ -0,0 +1,267 @@
+// SPDX-License-Identifier: EUPL-1.2
+use std::iter::zip;
+
+use counter::Counter;
+
+use crate::solver::char_trait::ControlChars;
+use crate::solver::infallible_char_collection::InfallibleCharCollection;
+
+#[cfg(feature = "pyo3")]
+use pyo3::prelude::*;
+
+/// Statistics of a letter in the unsolved positions of all matching words.
+#[cfg_attr(feature = "pyo3", pyclass(eq, frozen, get_all, skip_from_py_object))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct LetterStatistic {
+    /// The letter.
+    pub letter: char,
+    /// The probability that the word contains the letter.
+    pub probability: f64,
+    /// The expected number of positions revealed by guessing the letter.
+    pub expected_reveals: f64,
+    /// The probability that guessing the letter is a miss.
+    pub miss_probability: f64,
+}
+
+#[cfg(feature = "pyo3")]
+#[pymethods]
+impl LetterStatistic {
+    fn __repr__(&self) -> String {
+        format!(
+            "<LetterStatistic letter={} probability={} expected_reveals={} miss_probability={}>",
+            self.letter,
+            self.probability,
+            self.expected_reveals,
+            self.miss_probability,
+        )
+    }
+}
+
+#[inline]
+fn unsolved_letters<'a, CC: InfallibleCharCollection + ?Sized>(
+    pattern: &'a [char],
+    word: &'a CC,
+) -> impl Iterator<Item = char> + 'a {
+    zip(pattern.iter(), word.iter_chars())
+        .filter(|(p, _)| p.is_normalised_wildcard())
+        .map(|(_, ch)| ch)
+}
+
+/// Counts of letters, ASCII letters are counted without hashing.
+#[derive(Clone, Debug)]
+struct LetterCounts {
+    ascii: [u32; 128],
+    other: Counter<char, u32>,
+}
+
+impl LetterCounts {
+    fn new() -> Self {
+        Self {
+            ascii: [0; 128],
+            other: Counter::new(),
+        }
+    }
+
+    #[inline]
+    fn increment(&mut self, letter: char) {
+        if let Some(count) = usize::try_from(u32::from(letter))
+            .ok()
+            .and_then(|index| self.ascii.get_mut(index))
+        {
+            *count += 1;
+        } else {
+            *self.other.entry(letter).or_insert(0) += 1;
+        }
+    }
+
+    fn get(&self, letter: char) -> u32 {
+        usize::try_from(u32::from(letter))
+            .ok()
+            .and_then(|index| self.ascii.get(index))
+            .copied()
+            .unwrap_or_else(|| self.other.get(&letter).copied().unwrap_or(0))
+    }
+
+    fn add(&mut self, other: &Self) {
+        for (count, other_count) in zip(&mut self.ascii, &other.ascii) {
+            *count += other_count;
+        }
+        for (letter, count) in &other.other {
+            *self.other.entry(*letter).or_insert(0) += count;
+        }
+    }
+
+    /// The letters ordered by their count, then by the letter.
+    fn most_common_ordered(&self) -> Vec<(char, u32)> {
+        let mut counts: Vec<(char, u32)> = (0_u8..128)
+            .map(char::from)
+            .zip(self.ascii)
+            .chain(self.other.iter().map(|(letter, count)| (*letter, *count)))
+            .filter(|(_, count)| *count > 0)
+            .collect();
+
+        counts.sort_unstable_by(|(letter_a, count_a), (letter_b, count_b)| {
+            count_b.cmp(count_a).then(letter_a.cmp(letter_b))
+        });
+        counts
+    }
+}
+
+/// Counts the letters in the unsolved positions of words matching a pattern.
+#[derive(Clone, Debug)]
+pub struct LetterFrequencyCounter {
+    pattern: Box<[char]>,
+    /// true for normal hangman mode
+    letters_in_pattern_have_no_other_occurrences: bool,
+    words_count: u32,
+    /// How often a letter occurs in the unsolved positions.
+    occurrences: LetterCounts,
+    /// In how many words a letter occurs in the unsolved positions.
+    words: LetterCounts,
+    /// The non-ASCII letters already counted in the current word.
+    seen: Vec<char>,
+}
+
+impl LetterFrequencyCounter {
+    #[must_use]
+    pub(crate) fn new(
+        pattern: &[char],
+        letters_in_pattern_have_no_other_occurrences: bool,
+    ) -> Self {
+        Self {
+            pattern: pattern.into(),
+            letters_in_pattern_have_no_other_occurrences,
+            words_count: 0,
+            occurrences: LetterCounts::new(),
+            words: LetterCounts::new(),
+            seen: Vec::new(),
+        }
+    }
+
+    /// Count the letters of a word matching the pattern.
+    #[inline]
+    pub fn add_word<CC: InfallibleCharCollection + ?Sized>(
+        &mut self,
+        word: &CC,
+    ) {
+        debug_assert_eq!(word.char_count(), self.pattern.len());
+
+        self.words_count = self.words_count.saturating_add(1);
+
+        let mut seen_ascii: u128 = 0;
+        self.seen.clear();
+        for letter in unsolved_letters(&self.pattern, word) {
+            self.occurrences.increment(letter);
+
+            let first_in_word = match u32::from(letter) {
+                code @ 0..128 => {
+                    let bit = 1 << code;
+                    let first_in_word = seen_ascii & bit == 0;
+                    seen_ascii |= bit;
+                    first_in_word
+                }
+                _ if self.seen.contains(&letter) => false,
+                _ => {
+                    self.seen.push(letter);
+                    true
+                }
+            };
+            if first_in_word {
+                self.words.increment(letter);
+            }
+        }
+    }
+
+    /// Add the counts of a counter of the same pattern, like the counter
+    /// of another chunk of the words.
+    pub fn merge(&mut self, other: &Self) {
+        debug_assert_eq!(self.pattern, other.pattern);
+
+        self.words_count = self.words_count.saturating_add(other.words_count);
+        self.occurrences.add(&other.occurrences);
+        self.words.add(&other.words);
+    }
+
+    /// The count of the words added to this counter.
+    #[inline]
+    #[must_use]
+    pub const fn words_count(&self) -> u32 {
+        self.words_count
+    }
+
+    /// The letters in the unsolved positions, ordered by their frequency.
+    ///
+    /// In normal mode this counts the words containing a letter,
+    /// in crossword mode this counts the occurrences of a letter.
+    #[must_use]
+    pub fn letter_frequency(&self) -> Vec<(char, u32)> {
+        if self.letters_in_pattern_have_no_other_occurrences {
+            self.words.most_common_ordered()
+        } else {
+            self.occurrences.most_common_ordered()
+        }
+    }
+
+    /// The statistics of the letters, in the same order as the letter frequency.
+    #[must_use]
+    pub fn letter_statistics(&self) -> Vec<LetterStatistic> {
+        let words_count = f64::from(self.words_count);
+
+        self.letter_frequency()
+            .into_iter()
+            .map(|(letter, _)| {
+                let probability =
+                    f64::from(self.words.get(letter)) / words_count;
+                let expected_reveals =
+                    f64::from(self.occurrences.get(letter)) / words_count;
+
+                LetterStatistic {
+                    letter,
+                    probability,
+                    expected_reveals,
+                    miss_probability: 1.0 - probability,
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LetterFrequencyCounter;
+
+    #[test]
+    fn test_letter_frequency_counter() {
+        let pattern = ['_', '_', '_', '_', 'e'];
+        let mut normal = LetterFrequencyCounter::new(&pattern, true);
+        let mut crossword = LetterFrequencyCounter::new(&pattern, false);
+        for word in ["süßüe", "tütee", "ssese"] {
+            normal.add_word(word);
+            crossword.add_word(word);
+        }
+
+        assert_eq!(normal.words_count(), 3);
+        assert_eq!(
+            normal.letter_frequency(),
+            [('e', 2), ('s', 2), ('ü', 2), ('t', 1), ('ß', 1)]
+        );
+        assert_eq!(
+            crossword.letter_frequency(),
+            [('s', 4), ('ü', 3), ('e', 2), ('t', 2), ('ß', 1)]
+        );
+
+        let mut merged = LetterFrequencyCounter::new(&pattern, true);
+        merged.merge(&normal);
+        merged.merge(&normal);
+        assert_eq!(merged.words_count(), 6);
+        assert_eq!(merged.letter_frequency().first(), Some(&('e', 4)));
+        assert_eq!(
+            merged
+                .letter_statistics()
+                .first()
+                .map(|s| s.expected_reveals),
+            Some(4.0 / 6.0)
+        );
+    }
+}
@@ -0,0 +1,229 @@
+// SPDX-License-Identifier: EUPL-1.2
+#[cfg(feature = "pyo3")]
+use std::collections::VecDeque;
+use std::iter::FusedIterator;
+
+use crate::language::StringChunkIter;
+#[cfg(feature = "pyo3")]
+use crate::language::WordSequence;
+use crate::solver::pattern::Pattern;
+
+#[cfg(feature = "pyo3")]
+use pyo3::prelude::*;
+
+#[inline]
+fn skip_remaining(words: &mut StringChunkIter) {
+    let skipped = words.nth(words.len());
+    debug_assert_eq!(skipped, None);
+}
+
+/// The next word matching the pattern.
+///
+/// `prefix_found` is true if a word with the prefix of the pattern was found.
+#[inline]
+fn next_match<'a>(
+    pattern: &Pattern,
+    words: &mut StringChunkIter<'a>,
+    prefix_found: &mut bool,
+) -> Option<&'a str> {
+    if pattern.matches_every_word() {
+        return words.next();
+    }
+
+    if pattern.first_letter_is_wildcard() {
+        return words.find(|word| pattern.matches(word));
+    }
+
+    while let Some(word) = words.next() {
+        if pattern.prefix_matches(&word) {
+            *prefix_found = true;
+            if pattern.matches(&word) {
+                return Some(word);
+            }
+        } else if *prefix_found {
+            // the words are sorted, so no other word has the prefix
+            skip_remaining(words);
+            return None;
+        }
+    }
+
+    None
+}
+
+/// Lazily iterates over the words matching a pattern.
+#[derive(Clone)]
+pub struct MatchesIter<'a> {
+    pattern: Pattern,
+    words: StringChunkIter<'a>,
+    /// true if a word with the prefix of the pattern was found
+    prefix_found: bool,
+}
+
+impl<'a> MatchesIter<'a> {
+    /// Iterate over the words with the length of the pattern.
+    #[must_use]
+    pub(super) const fn new(
+        pattern: Pattern,
+        words: StringChunkIter<'a>,
+    ) -> Self {
+        Self {
+            pattern,
+            words,
+            prefix_found: false,
+        }
+    }
+
+    /// The pattern the words are matched against.
+    #[must_use]
+    pub const fn pattern(&self) -> &Pattern {
+        &self.pattern
+    }
+}
+
+impl FusedIterator for MatchesIter<'_> {}
+
+impl<'a> Iterator for MatchesIter<'a> {
+    type Item = &'a str;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        next_match(&self.pattern, &mut self.words, &mut self.prefix_found)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.pattern.matches_every_word() {
+            self.words.size_hint()
+        } else {
+            (0, Some(self.words.len()))
+        }
+    }
+}
+
+/// The count of words `PyMatchesIter` matches without the GIL at once.
+#[cfg(feature = "pyo3")]
+const CHUNK_SIZE: usize = 4096;
+
+/// Lazily iterates over the words matching a pattern from Python.
+///
+/// The words are matched in chunks without holding the GIL.
+#[cfg(feature = "pyo3")]
+#[pyclass(name = "MatchesIter", skip_from_py_object)]
+pub struct PyMatchesIter {
+    pattern: Pattern,
+    words: WordSequence,
+    /// The index of the first word not matched yet
+    position: usize,
+    /// true if a word with the prefix of the pattern was found
+    prefix_found: bool,
+    /// The indices of the matched words not yielded yet
+    matches: VecDeque<usize>,
+}
+
+#[cfg(feature = "pyo3")]
+impl PyMatchesIter {
+    #[must_use]
+    pub(crate) const fn new(pattern: Pattern, words: WordSequence) -> Self {
+        let position = if words.word_char_count() == pattern.pattern().len() {
+            0
+        } else {
+            words.len()
+        };
+
+        Self {
+            pattern,
+            words,
+            position,
+            prefix_found: false,
+            matches: VecDeque::new(),
+        }
+    }
+
+    /// Match chunks of words until a word matches or all words are matched.
+    fn refill(&mut self) {
+        while self.matches.is_empty() && self.position < self.words.len() {
+            let end = (self.position + CHUNK_SIZE).min(self.words.len());
+            let chunk = self.words.slice(self.position..end);
+            let mut words = chunk.iter();
+            while next_match(&self.pattern, &mut words, &mut self.prefix_found)
+                .is_some()
+            {
+                self.matches.push_back(end - words.len() - 1);
+            }
+            self.position = end;
+        }
+    }
+}
+
+#[cfg(feature = "pyo3")]
+#[pymethods]
+impl PyMatchesIter {
+    #[must_use]
+    const fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    #[must_use]
+    fn __next__(&mut self, py: Python<'_>) -> Option<&str> {
+        if self.matches.is_empty() {
+            py.detach(|| self.refill());
+        }
+        let index = self.matches.pop_front()?;
+        self.words.get(index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use unwrap_infallible::UnwrapInfallible;
+
+    use crate::Language;
+    use crate::solver::pattern::Pattern;
+    use crate::solver::word_selection::WordSelection;
+
+    #[test]
+    fn test_matches_iter_equals_solve() {
+        for (pattern, invalid, normal_mode) in [
+            ("__r_el_ier", "ix", true),
+            ("__r_el_ier", "", false),
+            ("t__t", "", true),
+            ("_____", "", true),
+            ("_____", "e", false),
+            ("e____", "a", true),
+        ] {
+            let pattern =
+                Pattern::new(pattern, invalid, normal_mode).unwrap_infallible();
+
+            for lang in Language::all() {
+                let words = lang.read_words(pattern.pattern().len());
+                let hr = pattern.solve(lang, None, WordSelection::default());
+
+                let mut letter_counter = pattern.letter_frequency_counter();
+                let matches: Vec<&str> = pattern
+                    .matches_iter(&words)
+                    .inspect(|word| letter_counter.add_word(*word))
+                    .collect();
+
+                assert_eq!(matches, hr.possible_words);
+                assert_eq!(
+                    letter_counter.words_count(),
+                    hr.matching_words_count
+                );
+                assert_eq!(
+                    letter_counter.letter_frequency(),
+                    hr.letter_frequency
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_matches_iter_with_other_length() {
+        let pattern = Pattern::new("t__t", "", true).unwrap_infallible();
+
+        assert_eq!(
+            pattern.matches_iter(&Language::En.read_words(5)).next(),
+            None
+        );
+    }
+}
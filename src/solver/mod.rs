@@ -6,21 +6,41 @@ use std::convert::Infallible;
 use js_sys::JsString;
 use unwrap_infallible::UnwrapInfallible;
 
-use crate::Language;
+use crate::language::WordSource;
 #[cfg(feature = "wasm-bindgen")]
 pub use crate::solver::hangman_result::WasmHangmanResult;
+#[cfg(feature = "wasm-bindgen")]
+pub use crate::solver::hangman_result::WasmLetterCount;
 
 pub use crate::solver::char_collection::CharCollection;
+#[cfg(feature = "wasm-bindgen")]
+pub use crate::solver::chunked::ChunkedSolve;
 pub use crate::solver::hangman_result::HangmanResult;
 pub use crate::solver::infallible_char_collection::InfallibleCharCollection;
+pub use crate::solver::letter_frequency::{
+    LetterFrequencyCounter, LetterStatistic,
+};
+pub use crate::solver::matches_iter::MatchesIter;
 pub use crate::solver::pattern::Pattern;
+pub use crate::solver::result_format::{
+    ResultDisplay, ResultField, ResultFormat, TemplatePart,
+};
+pub use crate::solver::word_selection::WordSelection;
 
 mod char_collection;
 mod char_trait;
 mod char_utils;
+#[cfg(any(feature = "wasm-bindgen", test))]
+mod chunked;
 mod hangman_result;
 mod infallible_char_collection;
+mod letter_frequency;
+pub mod matches_iter;
+pub mod parallel;
 mod pattern;
+pub mod random;
+mod result_format;
+mod word_selection;
 
 #[inline]
 #[allow(dead_code)]
@@ -28,8 +48,9 @@ pub fn solve<E1, E2, Err: From<E1> + From<E2>>(
     pattern: &(impl CharCollection<Error = E1> + ?Sized),
     invalid_letters: &(impl CharCollection<Error = E2> + ?Sized),
     letters_in_pattern_have_no_other_occurrences: bool,
-    language: Language,
+    language: impl Into<WordSource>,
     max_words_to_collect: Option<usize>,
+    selection: WordSelection,
 ) -> Result<HangmanResult, Err> {
     let pattern = Pattern::new::<E1, E2, Err>(
         pattern,
@@ -37,7 +58,7 @@ pub fn solve<E1, E2, Err: From<E1> + From<E2>>(
         letters_in_pattern_have_no_other_occurrences,
     )?;
 
-    Ok(pattern.solve(language, max_words_to_collect))
+    Ok(pattern.solve(language, max_words_to_collect, selection))
 }
 
 #[inline]
@@ -46,8 +67,9 @@ pub fn solve_infallible(
     pattern: &(impl CharCollection<Error = Infallible> + ?Sized),
     invalid_letters: &(impl CharCollection<Error = Infallible> + ?Sized),
     letters_in_pattern_have_no_other_occurrences: bool,
-    language: Language,
+    language: impl Into<WordSource>,
     max_words_to_collect: Option<usize>,
+    selection: WordSelection,
 ) -> HangmanResult {
     solve(
         pattern,
@@ -55,6 +77,7 @@ pub fn solve_infallible(
         letters_in_pattern_have_no_other_occurrences,
         language,
         max_words_to_collect,
+        selection,
     )
     .unwrap_infallible()
 }
@@ -67,6 +90,7 @@ pub fn solve_js<'a>(
     pattern_string: &JsString,
     invalid_letters: &JsString,
     max_words_to_collect: Option<usize>,
+    selection: WordSelection,
     crossword_mode: bool,
 ) -> WasmHangmanResult {
     use unwrap_infallible::UnwrapInfallible as _;
@@ -75,13 +99,15 @@ pub fn solve_js<'a>(
         Pattern::new(pattern_string, invalid_letters, !crossword_mode)
             .unwrap_infallible();
 
-    pattern.solve_with_words(all_words, max_words_to_collect)
+    pattern.solve_with_words(all_words, max_words_to_collect, selection)
 }
 
 #[cfg(test)]
 mod test {
     use unwrap_infallible::UnwrapInfallible;
 
+    use super::WordSelection;
+
     #[test]
     pub fn test_solve_no_max_words() {
         let hr = super::solve(
@@ -90,6 +116,7 @@ mod test {
             true,
             crate::Language::DeUmlauts,
             None,
+            WordSelection::default(),
         )
         .unwrap_infallible();
 
@@ -113,7 +140,7 @@ mod test {
             hr.possible_words,
             vec!["gürteltier", "murmeltier", "wurzelbier"]
         );
-        assert_eq!(hr.language, crate::Language::DeUmlauts);
+        assert_eq!(hr.language, Some(crate::Language::DeUmlauts));
     }
 
     #[test]
@@ -124,6 +151,7 @@ mod test {
             true,
             crate::Language::DeUmlauts,
             Some(1),
+            WordSelection::default(),
         )
         .unwrap_infallible();
 
@@ -144,6 +172,263 @@ mod test {
         );
         assert_eq!(hr.matching_words_count, 3);
         assert_eq!(hr.possible_words, vec!["gürteltier"]);
-        assert_eq!(hr.language, crate::Language::DeUmlauts);
+        assert_eq!(hr.next_offset, Some(1));
+        assert_eq!(hr.language, Some(crate::Language::DeUmlauts));
+    }
+
+    #[test]
+    pub fn test_solve_pages() {
+        let mut words = vec![];
+        let mut offset = Some(0);
+
+        while let Some(current_offset) = offset {
+            let hr = super::solve_infallible(
+                "__r_el_ier",
+                &['i', 'r', 'x', 'ä'],
+                true,
+                crate::Language::DeUmlauts,
+                Some(2),
+                WordSelection::First {
+                    offset: current_offset,
+                },
+            );
+
+            assert_eq!(hr.matching_words_count, 3);
+            assert_eq!(hr.letter_frequency.first(), Some(&('t', 2)));
+            words.extend(hr.possible_words);
+            offset = hr.next_offset;
+        }
+
+        assert_eq!(words, vec!["gürteltier", "murmeltier", "wurzelbier"]);
+
+        let hr = super::solve_infallible(
+            "__r_el_ier",
+            &['i', 'r', 'x', 'ä'],
+            true,
+            crate::Language::DeUmlauts,
+            None,
+            WordSelection::First { offset: 10 },
+        );
+
+        assert_eq!(hr.matching_words_count, 3);
+        assert!(hr.possible_words.is_empty());
+        assert_eq!(hr.next_offset, None);
+    }
+
+    #[test]
+    pub fn test_solve_letter_statistics() {
+        let hr = super::solve_infallible(
+            "__r_el_ier",
+            &['i', 'r', 'x', 'ä'],
+            true,
+            crate::Language::DeUmlauts,
+            Some(0),
+            WordSelection::default(),
+        );
+
+        assert_eq!(hr.matching_words_count, 3);
+        assert!(hr.possible_words.is_empty());
+        assert_eq!(hr.letter_statistics.len(), hr.letter_frequency.len());
+
+        for (statistic, (letter, _)) in
+            hr.letter_statistics.iter().zip(&hr.letter_frequency)
+        {
+            assert_eq!(statistic.letter, *letter);
+            assert!(
+                (statistic.probability + statistic.miss_probability - 1.0)
+                    .abs()
+                    < f64::EPSILON
+            );
+        }
+
+        let statistic = |letter: char| {
+            hr.letter_statistics
+                .iter()
+                .find(|statistic| statistic.letter == letter)
+                .copied()
+                .expect("letter is in the statistics")
+        };
+
+        let t = statistic('t');
+        assert!((t.probability - 2.0 / 3.0).abs() < f64::EPSILON);
+        assert!((t.expected_reveals - 1.0).abs() < f64::EPSILON);
+
+        let m = statistic('m');
+        assert!((m.probability - 1.0 / 3.0).abs() < f64::EPSILON);
+        assert!((m.expected_reveals - 2.0 / 3.0).abs() < f64::EPSILON);
+        assert!((m.miss_probability - 2.0 / 3.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    pub fn test_solve_crossword_letter_frequency() {
+        let hr = super::solve_infallible(
+            "__r_el_ier",
+            &['x', 'ä'],
+            false,
+            crate::Language::DeUmlauts,
+            None,
+            WordSelection::default(),
+        );
+
+        assert!(hr.matching_words_count >= 3);
+        assert!(hr.possible_words.contains(&"murmeltier".into()));
+
+        for (statistic, (letter, count)) in
+            hr.letter_statistics.iter().zip(&hr.letter_frequency)
+        {
+            assert_eq!(statistic.letter, *letter);
+            assert!(
+                (statistic.expected_reveals
+                    - f64::from(*count) / f64::from(hr.matching_words_count))
+                .abs()
+                    < f64::EPSILON
+            );
+        }
+    }
+
+    #[test]
+    pub fn test_solve_word_list() {
+        let words = crate::language::WordList::new([
+            "test", "Text", "tent", "toast", "te",
+        ]);
+        let hr = super::solve_infallible(
+            "te_t",
+            "n",
+            true,
+            words.clone(),
+            None,
+            WordSelection::default(),
+        );
+
+        assert_eq!(hr.possible_words, ["test", "text"]);
+        assert_eq!(hr.matching_words_count, 2);
+        assert_eq!(hr.language, None);
+        assert!(!words.read_words(4).contains("aaaa"));
+    }
+
+    #[test]
+    pub fn test_pattern_properties() {
+        let pattern =
+            super::Pattern::new("T?s t", "xät", true).unwrap_infallible();
+        assert_eq!(pattern.pattern(), ['t', '_', 's', 't']);
+        assert_eq!(pattern.invalid_letters_not_in_pattern(), ['x', 'ä']);
+        assert_eq!(pattern.known_letters_count(), 3);
+        assert!(!pattern.is_crossword());
+
+        let pattern =
+            super::Pattern::new("#e__", "", false).unwrap_infallible();
+        assert_eq!(pattern.known_letters_count(), 1);
+        assert!(pattern.invalid_letters_not_in_pattern().is_empty());
+        assert!(pattern.is_crossword());
+    }
+
+    #[test]
+    pub fn test_solve_cancellable() {
+        use std::sync::atomic::AtomicBool;
+
+        let pattern =
+            super::Pattern::new("_____", "e", false).unwrap_infallible();
+        let solve = |cancelled| {
+            pattern.solve_cancellable(
+                crate::Language::En,
+                Some(3),
+                WordSelection::default(),
+                &AtomicBool::new(cancelled),
+            )
+        };
+
+        assert!(solve(false).is_some_and(|result| result
+            == pattern.solve(
+                crate::Language::En,
+                Some(3),
+                WordSelection::default()
+            )));
+        assert!(solve(true).is_none());
+    }
+
+    #[test]
+    pub fn test_solve_sample() {
+        let solve = |max_words_to_collect, seed| {
+            super::solve_infallible(
+                "_____",
+                "e",
+                true,
+                crate::Language::En,
+                max_words_to_collect,
+                WordSelection::Sample { seed },
+            )
+        };
+
+        let all_words = solve(None, 0);
+        assert_eq!(
+            all_words.possible_words.len(),
+            usize::try_from(all_words.matching_words_count)
+                .expect("count fits into usize")
+        );
+        assert_eq!(all_words.next_offset, None);
+
+        let sample = solve(Some(10), 1);
+        assert_eq!(sample.possible_words.len(), 10);
+        assert_eq!(sample.matching_words_count, all_words.matching_words_count);
+        assert_eq!(sample.letter_frequency, all_words.letter_frequency);
+        assert_eq!(sample.next_offset, None);
+        assert!(sample.possible_words.is_sorted());
+        assert!(
+            sample
+                .possible_words
+                .iter()
+                .all(|word| all_words.possible_words.contains(word))
+        );
+
+        assert_eq!(sample.possible_words, solve(Some(10), 1).possible_words);
+        assert_ne!(sample.possible_words, solve(Some(10), 2).possible_words);
+        assert_ne!(
+            sample.possible_words,
+            all_words
+                .possible_words
+                .get(..10)
+                .expect("more than 10 words")
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    pub fn test_solve_serialize() {
+        use serde_json::json;
+
+        let hr = super::solve_infallible(
+            "__r_el_ier",
+            "ix",
+            true,
+            crate::Language::DeUmlauts,
+            Some(2),
+            WordSelection::default(),
+        );
+
+        let json = serde_json::to_value(&hr).expect("serializable");
+        assert_eq!(json.pointer("/input"), Some(&json!("__r_el_ier")));
+        assert_eq!(json.pointer("/invalid"), Some(&json!("x")));
+        assert_eq!(json.pointer("/count"), Some(&json!(3)));
+        assert_eq!(
+            json.pointer("/words"),
+            Some(&json!(["gürteltier", "murmeltier"]))
+        );
+        assert_eq!(json.pointer("/next_offset"), Some(&json!(2)));
+        assert_eq!(json.pointer("/language"), Some(&json!("de_umlauts")));
+        assert_eq!(json.pointer("/letter_frequency/t"), Some(&json!(2)));
+        assert_eq!(
+            json.pointer("/letter_statistics/0/letter"),
+            Some(&json!("t"))
+        );
+
+        let text = serde_json::to_string(&hr).expect("serializable");
+        assert!(text.contains(r#""letter_frequency":{"t":2,"u":2,"#));
+
+        let language: crate::Language = serde_json::from_value(
+            json.get("language").cloned().unwrap_or_default(),
+        )
+        .expect("valid language");
+        assert_eq!(language, crate::Language::DeUmlauts);
+        assert!(serde_json::from_str::<crate::Language>(r#""xx""#).is_err());
     }
 }
@@ -0,0 +1,72 @@
+// SPDX-License-Identifier: EUPL-1.2
+use std::num::NonZeroUsize;
+use std::panic::resume_unwind;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+/// The count of threads to use for `items` items.
+#[must_use]
+pub fn thread_count(items: usize) -> usize {
+    thread::available_parallelism()
+        .map_or(1, NonZeroUsize::get)
+        .min(items)
+        .max(1)
+}
+
+/// Map the items on all cores, keeping their order.
+///
+/// The threads take the next item when done, so slow items don't block
+/// the other threads.
+pub fn par_map<T: Sync, R: Send>(
+    items: &[T],
+    function: impl Fn(&T) -> R + Sync,
+) -> Vec<R> {
+    let threads = thread_count(items.len());
+    if threads == 1 {
+        return items.iter().map(function).collect();
+    }
+
+    let next_index = AtomicUsize::new(0);
+    let mut results: Vec<(usize, R)> = thread::scope(|scope| {
+        let workers: Vec<_> = (0..threads)
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut results = Vec::new();
+                    loop {
+                        let index = next_index.fetch_add(1, Ordering::Relaxed);
+                        let Some(item) = items.get(index) else {
+                            return results;
+                        };
+                        results.push((index, function(item)));
+                    }
+                })
+            })
+            .collect();
+
+        workers
+            .into_iter()
+            .flat_map(|worker| {
+                worker.join().unwrap_or_else(|e| resume_unwind(e))
+            })
+            .collect()
+    });
+
+    results.sort_unstable_by_key(|(index, _)| *index);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::par_map;
+
+    #[test]
+    fn test_par_map() {
+        let items: Vec<u64> = (0..1000).collect();
+
+        assert_eq!(
+            par_map(&items, |item| item * 2),
+            items.iter().map(|item| item * 2).collect::<Vec<_>>()
+        );
+        assert_eq!(par_map(&[] as &[u64], |item| *item), Vec::<u64>::new());
+    }
+}
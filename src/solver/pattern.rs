@@ -1,8 +1,10 @@
 // SPDX-License-Identifier: EUPL-1.2
+use std::borrow::Cow;
 use std::char;
 use std::iter::zip;
+use std::sync::atomic::{AtomicBool, Ordering};
 
-use crate::language::Language;
+use crate::language::{Language, WordSequence, WordSource};
 use crate::solver::char_collection::CharCollection;
 use crate::solver::char_trait::ControlChars;
 use crate::solver::char_utils::CharUtils;
@@ -10,14 +12,42 @@ use crate::solver::hangman_result::HangmanResult;
 #[cfg(feature = "wasm-bindgen")]
 use crate::solver::hangman_result::WasmHangmanResult;
 use crate::solver::infallible_char_collection::InfallibleCharCollection;
+use crate::solver::letter_frequency::LetterFrequencyCounter;
+use crate::solver::matches_iter::MatchesIter;
+#[cfg(feature = "pyo3")]
+use crate::solver::matches_iter::PyMatchesIter;
+use crate::solver::random::reservoir_sample;
+use crate::solver::word_selection::WordSelection;
 
-use counter::Counter;
 use itertools::Itertools;
 
 #[cfg(feature = "wasm-bindgen")]
 use js_sys::JsString;
+#[cfg(feature = "pyo3")]
+use pyo3::prelude::*;
+
+/// The offset of the first word after the collected words,
+/// if there are more matching words.
+#[inline]
+fn next_offset(
+    selection: WordSelection,
+    collected_words_count: usize,
+    matching_words_count: u32,
+) -> Option<usize> {
+    let WordSelection::First { offset } = selection else {
+        return None;
+    };
+    let next_offset = offset.saturating_add(collected_words_count);
+
+    usize::try_from(matching_words_count)
+        .is_ok_and(|count| next_offset < count)
+        .then_some(next_offset)
+}
 
+/// A normalised pattern with the invalid letters, solvable with any language.
+#[cfg_attr(feature = "pyo3", pyclass(frozen, skip_from_py_object))]
 #[allow(clippy::struct_field_names)]
+#[derive(Clone)]
 pub struct Pattern {
     invalid_letters: Vec<char>,
     pattern: Vec<char>,
@@ -40,6 +70,57 @@ impl Pattern {
     pub const fn pattern(&self) -> &[char] {
         self.pattern.as_slice()
     }
+
+    /// The sorted invalid letters, without the letters of the pattern
+    /// added in normal mode.
+    #[must_use]
+    pub fn invalid_letters_not_in_pattern(&self) -> Vec<char> {
+        let mut invalid: Vec<char> = self
+            .invalid_letters
+            .iter()
+            .filter(|ch| !self.pattern.contains(*ch))
+            .copied()
+            .collect();
+
+        invalid.sort_unstable();
+        invalid
+    }
+
+    /// true for crossword mode, where letters in the pattern can appear
+    /// in other positions
+    #[must_use]
+    pub const fn is_crossword(&self) -> bool {
+        !self.letters_in_pattern_have_no_other_occurrences
+    }
+
+    /// Lazily iterate over the words matching this pattern.
+    #[must_use]
+    pub fn matches_iter<'a>(&self, words: &'a WordSequence) -> MatchesIter<'a> {
+        let mut iter = words.iter();
+        if words.word_char_count() != self.pattern.len() {
+            iter.nth(iter.len());
+        }
+
+        MatchesIter::new(self.clone(), iter)
+    }
+
+    /// Lazily iterate over the words of a language matching this pattern.
+    #[must_use]
+    pub fn language_matches_iter(
+        &self,
+        language: Language,
+    ) -> MatchesIter<'static> {
+        MatchesIter::new(self.clone(), language.words(self.pattern.len()))
+    }
+
+    /// Create an empty counter for the letters of words matching this pattern.
+    #[must_use]
+    pub fn letter_frequency_counter(&self) -> LetterFrequencyCounter {
+        LetterFrequencyCounter::new(
+            &self.pattern,
+            self.letters_in_pattern_have_no_other_occurrences,
+        )
+    }
 }
 
 #[expect(clippy::used_underscore_items)]
@@ -120,13 +201,20 @@ impl Pattern {
 
     #[inline]
     #[must_use]
-    const fn first_letter_is_wildcard(&self) -> bool {
+    pub(super) const fn first_letter_is_wildcard(&self) -> bool {
         self.prefix.is_empty()
     }
 
+    /// true if every word with the length of the pattern matches
+    #[inline]
+    #[must_use]
+    pub(super) fn matches_every_word(&self) -> bool {
+        self.invalid_letters.is_empty() && self.known_letters_count() == 0
+    }
+
     #[must_use]
     #[inline]
-    fn prefix_matches<CC: InfallibleCharCollection + ?Sized>(
+    pub(super) fn prefix_matches<CC: InfallibleCharCollection + ?Sized>(
         &self,
         word: &&CC,
     ) -> bool {
@@ -149,7 +237,7 @@ impl Pattern {
 
     #[must_use]
     #[inline]
-    fn matches<CC: InfallibleCharCollection + ?Sized>(
+    pub(super) fn matches<CC: InfallibleCharCollection + ?Sized>(
         &self,
         word: &&CC,
     ) -> bool {
@@ -175,9 +263,10 @@ impl Pattern {
         true
     }
 
+    /// The count of the letters in the pattern that aren't wildcards.
     #[inline]
     #[must_use]
-    fn known_letters_count(&self) -> usize {
+    pub fn known_letters_count(&self) -> usize {
         debug_assert_eq!(
             self.known_letters_count,
             self.pattern
@@ -200,87 +289,202 @@ impl Pattern {
         &self,
         words: &'b mut T,
         max_words_to_collect: Option<usize>,
-    ) -> (u32, Counter<char, u32>, Vec<&'a CC>) {
-        let mut letter_counter: Counter<char, u32> = Counter::new();
+        selection: WordSelection,
+    ) -> (LetterFrequencyCounter, Vec<&'a CC>) {
+        let mut letter_counter = self.letter_frequency_counter();
+
+        let words_vec: Vec<&'a CC> = {
+            let mut words =
+                words.inspect(|word| letter_counter.add_word(*word));
+
+            match (selection, max_words_to_collect) {
+                (WordSelection::First { offset }, max_words_to_collect) => {
+                    words.by_ref().take(offset).for_each(drop);
+
+                    if let Some(n) = max_words_to_collect {
+                        let words_vec = words.by_ref().take(n).collect();
+                        words.for_each(drop);
+                        words_vec
+                    } else {
+                        words.collect()
+                    }
+                }
+                (WordSelection::Sample { seed }, Some(n)) => {
+                    reservoir_sample(words, n, seed)
+                }
+                (WordSelection::Sample { .. }, None) => words.collect(),
+            }
+        };
 
-        let update_counter: fn(&mut Counter<char, u32>, &CC) =
-            if self.letters_in_pattern_have_no_other_occurrences {
-                |counter, word| counter.update(word.iter_chars().unique())
-            } else {
-                |counter, word| counter.update(word.iter_chars())
-            };
+        (letter_counter, words_vec)
+    }
 
-        let mut words =
-            words.inspect(|word| update_counter(&mut letter_counter, word));
+    /// Solve the pattern with the words of a language or a word list.
+    ///
+    /// Use `HangmanResult::next_offset` to get the next page of words.
+    #[inline]
+    pub fn solve(
+        &self,
+        words: impl Into<WordSource>,
+        max_words_to_collect: Option<usize>,
+        selection: WordSelection,
+    ) -> HangmanResult {
+        let words: WordSource = words.into();
+
+        self.solve_sequence(
+            &words.read_words(self.pattern.len()),
+            words.language(),
+            max_words_to_collect,
+            selection,
+        )
+    }
 
-        let (words_vec, additional_count): (Vec<&'a CC>, usize) =
-            if let Some(n) = max_words_to_collect {
-                (words.by_ref().take(n).collect(), words.count())
-            } else {
-                (words.collect(), 0)
-            };
+    /// Solve the pattern with the words of a language or a word list
+    /// with the length of the pattern.
+    #[inline]
+    pub fn solve_sequence(
+        &self,
+        words: &WordSequence,
+        language: Option<Language>,
+        max_words_to_collect: Option<usize>,
+        selection: WordSelection,
+    ) -> HangmanResult {
+        let (possible_words, letter_counter) = self._solve_sequence(
+            words,
+            max_words_to_collect,
+            selection,
+            || true,
+        );
 
-        let words_count = u32::try_from(additional_count + words_vec.len())
-            .unwrap_or(u32::MAX);
+        self.result(language, selection, possible_words, &letter_counter)
+    }
 
-        if self.letters_in_pattern_have_no_other_occurrences {
-            for letter in &self.pattern {
-                if let Some(count) = letter_counter.remove(letter) {
-                    debug_assert_eq!(count, words_count);
-                }
-            }
-        } else {
-            for letter in self
-                .pattern
-                .iter()
-                .filter(|char| !char.is_normalised_wildcard())
-            {
-                if let Some(new_count) = letter_counter
-                    .get(letter)
-                    .and_then(|c| c.checked_sub(words_count))
-                    .filter(|&c| c != 0)
-                {
-                    letter_counter.insert(*letter, new_count);
-                } else {
-                    letter_counter.remove(letter);
-                }
-            }
-        }
+    /// Solve the pattern like `solve`, but stop once `cancelled` is set.
+    ///
+    /// Returns `None` if the solve was cancelled.
+    #[inline]
+    pub fn solve_cancellable(
+        &self,
+        words: impl Into<WordSource>,
+        max_words_to_collect: Option<usize>,
+        selection: WordSelection,
+        cancelled: &AtomicBool,
+    ) -> Option<HangmanResult> {
+        let words: WordSource = words.into();
+        let (possible_words, letter_counter) = self._solve_sequence(
+            &words.read_words(self.pattern.len()),
+            max_words_to_collect,
+            selection,
+            || !cancelled.load(Ordering::Relaxed),
+        );
 
-        (words_count, letter_counter, words_vec)
+        if cancelled.load(Ordering::Relaxed) {
+            return None;
+        }
+        Some(self.result(
+            words.language(),
+            selection,
+            possible_words,
+            &letter_counter,
+        ))
     }
 
+    /// Solve the pattern with sorted words of a language, like the
+    /// words that matched a previous pattern.
+    ///
+    /// The words need to have the length of the pattern.
     #[inline]
-    pub fn solve(
+    pub fn solve_words(
         &self,
+        words: &[&'static str],
         language: Language,
         max_words_to_collect: Option<usize>,
+        selection: WordSelection,
     ) -> HangmanResult {
-        let mut all_words = language.read_words(self.pattern.len()).into_iter();
-        let (possible_words, letter_frequency, matching_words_count) =
-            self._solve_internal(&mut all_words, max_words_to_collect);
+        let (possible_words, letter_counter) = self._solve_internal(
+            &mut words.iter().copied(),
+            max_words_to_collect,
+            selection,
+        );
 
-        let mut invalid: Vec<char> = self
-            .invalid_letters
-            .iter()
-            .filter(|ch| !self.pattern.contains(*ch))
-            .copied()
-            .collect();
+        self.result(
+            Some(language),
+            selection,
+            possible_words.into_iter().map(Cow::Borrowed).collect(),
+            &letter_counter,
+        )
+    }
 
-        invalid.sort_unstable();
+    /// Keep only the words matching the pattern.
+    ///
+    /// The words need to have the length of the pattern.
+    #[inline]
+    pub fn retain_matches(&self, words: &mut Vec<&'static str>) {
+        words.retain(|word| self.matches(word));
+    }
+
+    #[inline]
+    pub(super) fn result(
+        &self,
+        language: Option<Language>,
+        selection: WordSelection,
+        possible_words: Vec<Cow<'static, str>>,
+        letter_counter: &LetterFrequencyCounter,
+    ) -> HangmanResult {
         HangmanResult {
             input: self.pattern.iter().collect(),
-            invalid,
+            invalid: self.invalid_letters_not_in_pattern(),
+            next_offset: next_offset(
+                selection,
+                possible_words.len(),
+                letter_counter.words_count(),
+            ),
             possible_words,
             language,
-            letter_frequency,
-            matching_words_count,
+            letter_frequency: letter_counter.letter_frequency(),
+            letter_statistics: letter_counter.letter_statistics(),
+            matching_words_count: letter_counter.words_count(),
         }
     }
 
+    /// Solve the pattern with the words until `keep_going` returns false.
+    ///
+    /// The words of languages are borrowed, the words of word lists copied.
     #[must_use]
     #[inline]
-    fn _solve_internal<
+    pub(super) fn _solve_sequence(
+        &self,
+        words: &WordSequence,
+        max_words_to_collect: Option<usize>,
+        selection: WordSelection,
+        mut keep_going: impl FnMut() -> bool,
+    ) -> (Vec<Cow<'static, str>>, LetterFrequencyCounter) {
+        if let Some(words) = words.static_iter() {
+            let (possible_words, letter_counter) = self._solve_internal(
+                &mut words.take_while(|_| keep_going()),
+                max_words_to_collect,
+                selection,
+            );
+            let possible_words =
+                possible_words.into_iter().map(Cow::Borrowed).collect();
+            return (possible_words, letter_counter);
+        }
+
+        let (possible_words, letter_counter) = self._solve_internal(
+            &mut words.iter().take_while(|_| keep_going()),
+            max_words_to_collect,
+            selection,
+        );
+        let possible_words = possible_words
+            .into_iter()
+            .map(|word| Cow::Owned(word.to_owned()))
+            .collect();
+        (possible_words, letter_counter)
+    }
+
+    #[must_use]
+    #[inline]
+    pub(super) fn _solve_internal<
         'a,
         'b,
         CC: InfallibleCharCollection + ?Sized + 'a,
@@ -289,34 +493,35 @@ impl Pattern {
         &self,
         all_words: &'b mut T,
         max_words_to_collect: Option<usize>,
-    ) -> (Vec<&'a CC>, Vec<(char, u32)>, u32) {
-        let (word_count, letter_frequency, words) =
-            if self.invalid_letters.is_empty()
-                && self.known_letters_count() == 0
-            {
-                self._collect_count_and_create_letter_frequency(
-                    all_words,
-                    max_words_to_collect,
-                )
-            } else if self.first_letter_is_wildcard() {
-                let mut filtered_words =
-                    all_words.filter(|word| self.matches(word));
-                self._collect_count_and_create_letter_frequency(
-                    &mut filtered_words,
-                    max_words_to_collect,
-                )
-            } else {
-                let mut filtered_words = all_words
-                    .skip_while(|word| !self.prefix_matches(word))
-                    .take_while(|word| self.prefix_matches(word))
-                    .filter(|word| self.matches(word));
-                self._collect_count_and_create_letter_frequency(
-                    &mut filtered_words,
-                    max_words_to_collect,
-                )
-            };
-
-        (words, letter_frequency.most_common_ordered(), word_count)
+        selection: WordSelection,
+    ) -> (Vec<&'a CC>, LetterFrequencyCounter) {
+        let (letter_counter, words) = if self.matches_every_word() {
+            self._collect_count_and_create_letter_frequency(
+                all_words,
+                max_words_to_collect,
+                selection,
+            )
+        } else if self.first_letter_is_wildcard() {
+            let mut filtered_words =
+                all_words.filter(|word| self.matches(word));
+            self._collect_count_and_create_letter_frequency(
+                &mut filtered_words,
+                max_words_to_collect,
+                selection,
+            )
+        } else {
+            let mut filtered_words = all_words
+                .skip_while(|word| !self.prefix_matches(word))
+                .take_while(|word| self.prefix_matches(word))
+                .filter(|word| self.matches(word));
+            self._collect_count_and_create_letter_frequency(
+                &mut filtered_words,
+                max_words_to_collect,
+                selection,
+            )
+        };
+
+        (words, letter_counter)
     }
 }
 
@@ -329,38 +534,129 @@ impl Pattern {
         &self,
         all_words: &'b mut T,
         max_words_to_collect: Option<usize>,
+        selection: WordSelection,
     ) -> WasmHangmanResult {
-        let (possible_words, letter_frequency, matching_words_count) =
-            self._solve_internal(all_words, max_words_to_collect);
+        let (possible_words, letter_counter) =
+            self._solve_internal(all_words, max_words_to_collect, selection);
 
-        let mut invalid: Vec<char> = self
-            .invalid_letters
-            .iter()
-            .filter(|ch| !self.pattern.contains(*ch))
-            .copied()
+        WasmHangmanResult {
+            next_offset: next_offset(
+                selection,
+                possible_words.len(),
+                letter_counter.words_count(),
+            ),
+            possible_words: possible_words.into_iter().cloned().collect(),
+            ..self
+                .result(None, selection, Vec::new(), &letter_counter)
+                .into()
+        }
+    }
+}
+
+#[cfg(feature = "pyo3")]
+#[pymethods]
+impl Pattern {
+    /// Parse a pattern, the chars in `wildcards` are used like `_`.
+    ///
+    /// Crossword mode means that letters in the pattern can appear in other positions.
+    #[new]
+    #[pyo3(signature = (pattern, invalid_letters = "", crossword = false, wildcards = ""))]
+    fn py_new(
+        pattern: &str,
+        invalid_letters: &str,
+        crossword: bool,
+        wildcards: &str,
+    ) -> Self {
+        use unwrap_infallible::UnwrapInfallible as _;
+
+        let pattern: String = pattern
+            .chars()
+            .map(|ch| {
+                if wildcards.contains(ch) {
+                    char::WILDCARD
+                } else {
+                    ch
+                }
+            })
+            .collect();
+        let invalid_letters: String = invalid_letters
+            .chars()
+            .filter(|ch| !wildcards.contains(*ch))
             .collect();
 
-        let mut letter_frequency_string: String = String::new();
+        Self::new(&pattern, &invalid_letters, !crossword).unwrap_infallible()
+    }
 
-        for (char, count) in letter_frequency {
-            if !letter_frequency_string.is_empty() {
-                letter_frequency_string.push_str(", ");
-            }
-            letter_frequency_string.push(char);
-            letter_frequency_string.push_str(": ");
-            letter_frequency_string.push_str(&count.to_string());
-        }
+    /// The normalised pattern with `_` as wildcard.
+    #[getter(pattern)]
+    fn py_pattern(&self) -> String {
+        self.pattern.iter().collect()
+    }
 
-        invalid.sort_unstable();
-        WasmHangmanResult {
-            input: JsString::from(self.pattern.iter().collect::<String>()),
-            invalid: JsString::from(invalid.iter().collect::<String>()),
-            possible_words: possible_words
-                .into_iter()
-                .map(JsString::to_string)
-                .collect(),
-            letter_frequency: JsString::from(letter_frequency_string),
-            matching_words_count,
-        }
+    /// The sorted invalid letters, without the letters of the pattern.
+    #[getter(invalid_letters)]
+    fn py_invalid_letters(&self) -> Vec<char> {
+        self.invalid_letters_not_in_pattern()
+    }
+
+    #[getter(known_letters_count)]
+    fn py_known_letters_count(&self) -> usize {
+        self.known_letters_count()
+    }
+
+    #[getter(crossword)]
+    const fn py_crossword(&self) -> bool {
+        self.is_crossword()
+    }
+
+    /// Check if a word matches the pattern, ignoring the case of the word.
+    #[pyo3(name = "matches")]
+    fn py_matches(&self, word: &str) -> bool {
+        let word = word.to_lowercase();
+
+        word.char_count() == self.pattern.len()
+            && !word.chars().any(|ch| ch.is_reserved())
+            && self.matches(&word.as_str())
+    }
+
+    /// Solve the pattern with the words of a language or a word list.
+    ///
+    /// The first `offset` matching words are skipped,
+    /// pass `next_offset` of the result to get the next words.
+    /// With a `seed` a random sample of the matching words is collected instead.
+    #[pyo3(name = "solve", signature = (language, max_words_to_collect, offset = 0, seed = None))]
+    fn py_solve(
+        &self,
+        py: Python<'_>,
+        language: WordSource,
+        max_words_to_collect: usize,
+        offset: usize,
+        seed: Option<u64>,
+    ) -> PyResult<HangmanResult> {
+        let selection = WordSelection::from_py_args(offset, seed)?;
+
+        Ok(py.detach(|| {
+            self.solve(language, Some(max_words_to_collect), selection)
+        }))
+    }
+
+    /// Lazily iterate over the words of a language or a word list
+    /// matching the pattern.
+    #[pyo3(name = "matches_iter")]
+    #[allow(clippy::needless_pass_by_value)]
+    fn py_matches_iter(&self, language: WordSource) -> PyMatchesIter {
+        PyMatchesIter::new(
+            self.clone(),
+            language.read_words(self.pattern.len()),
+        )
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "<Pattern pattern={} invalid={:?} crossword={}>",
+            self.pattern.iter().collect::<String>(),
+            self.invalid_letters_not_in_pattern(),
+            if self.is_crossword() { "True" } else { "False" },
+        )
     }
 }
@@ -0,0 +1,146 @@
+// SPDX-License-Identifier: EUPL-1.2
+
+/// A small deterministic pseudo random number generator (`SplitMix64`).
+///
+/// The same seed always produces the same numbers on every platform.
+#[derive(Clone, Debug)]
+pub struct Random {
+    state: u64,
+}
+
+impl Random {
+    #[inline]
+    #[must_use]
+    pub const fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    #[inline]
+    pub const fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// A uniformly distributed number in `0..bound`.
+    #[inline]
+    #[expect(clippy::cast_possible_truncation)]
+    pub fn below(&mut self, bound: usize) -> usize {
+        debug_assert_ne!(bound, 0);
+        let bound = bound as u64;
+        let threshold = bound.wrapping_neg() % bound;
+
+        loop {
+            let product = u128::from(self.next_u64()) * u128::from(bound);
+            if product as u64 >= threshold {
+                // smaller than bound, so it fits into an usize
+                return (product >> 64) as usize;
+            }
+        }
+    }
+}
+
+/// A sample of up to `n` of the items pushed so far, keeping their order.
+///
+/// Sampling all items at once or in chunks gives the same sample.
+#[derive(Clone, Debug)]
+pub struct Reservoir<T> {
+    random: Random,
+    size: usize,
+    seen: usize,
+    items: Vec<(usize, T)>,
+}
+
+impl<T> Reservoir<T> {
+    #[must_use]
+    pub const fn new(size: usize, seed: u64) -> Self {
+        Self {
+            random: Random::new(seed),
+            size,
+            seen: 0,
+            items: Vec::new(),
+        }
+    }
+
+    pub fn push(&mut self, item: T) {
+        let index = self.seen;
+        self.seen = self.seen.saturating_add(1);
+
+        if index < self.size {
+            self.items.push((index, item));
+        } else if let Some(slot) =
+            self.items.get_mut(self.random.below(self.seen))
+        {
+            *slot = (index, item);
+        }
+    }
+
+    /// The items sampled so far, in the order they were pushed.
+    #[cfg(any(feature = "wasm-bindgen", test))]
+    #[must_use]
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.clone().into_vec()
+    }
+
+    /// The sampled items, in the order they were pushed.
+    #[must_use]
+    pub fn into_vec(mut self) -> Vec<T> {
+        self.items.sort_unstable_by_key(|(index, _)| *index);
+        self.items.into_iter().map(|(_, item)| item).collect()
+    }
+}
+
+impl<T> Extend<T> for Reservoir<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, items: I) {
+        for item in items {
+            self.push(item);
+        }
+    }
+}
+
+/// Sample up to `n` items with reservoir sampling, keeping their order.
+#[must_use]
+pub fn reservoir_sample<T>(
+    items: impl Iterator<Item = T>,
+    n: usize,
+    seed: u64,
+) -> Vec<T> {
+    let mut reservoir = Reservoir::new(n, seed);
+    reservoir.extend(items);
+    reservoir.into_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Random, reservoir_sample};
+
+    #[test]
+    fn test_random_is_deterministic() {
+        let mut a = Random::new(42);
+        let mut b = Random::new(42);
+
+        for bound in 1..1000 {
+            let value = a.below(bound);
+            assert!(value < bound);
+            assert_eq!(value, b.below(bound));
+        }
+    }
+
+    #[test]
+    fn test_reservoir_sample() {
+        assert_eq!(reservoir_sample(0..10, 20, 1), (0..10).collect::<Vec<_>>());
+        assert_eq!(reservoir_sample(0..10, 0, 1), Vec::<i32>::new());
+
+        let sample = reservoir_sample(0..10_000, 10, 7);
+        assert_eq!(sample.len(), 10);
+        assert!(sample.is_sorted());
+        assert_eq!(sample, reservoir_sample(0..10_000, 10, 7));
+        assert_ne!(sample, reservoir_sample(0..10_000, 10, 8));
+        assert_ne!(sample, (0..10).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,329 @@
+// SPDX-License-Identifier: EUPL-1.2
+use std::fmt::{self, Display, Write};
+
+use itertools::Itertools;
+
+use crate::language::Language;
+use crate::solver::hangman_result::HangmanResult;
+
+/// A value of a result, used in templates as `{name}`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ResultField {
+    Input,
+    Invalid,
+    Count,
+    /// The collected words, separated by `, `.
+    Words,
+    /// The letters with their frequency, like `e: 3, n: 2`.
+    Letters,
+    Language,
+    /// The next offset, or an empty string.
+    NextOffset,
+}
+
+impl ResultField {
+    fn from_string(name: &str) -> Option<Self> {
+        match name {
+            "input" => Some(Self::Input),
+            "invalid" => Some(Self::Invalid),
+            "count" => Some(Self::Count),
+            "words" => Some(Self::Words),
+            "letters" => Some(Self::Letters),
+            "language" => Some(Self::Language),
+            "next_offset" => Some(Self::NextOffset),
+            _ => None,
+        }
+    }
+}
+
+/// A part of a template of `ResultFormat::Template`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TemplatePart {
+    Literal(String),
+    Field(ResultField),
+}
+
+/// How a `HangmanResult` is formatted.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum ResultFormat {
+    /// The lists are shortened to the width, like the `Display` impl.
+    #[default]
+    Default,
+    /// Like `Default`, but with all words and letters.
+    Full,
+    /// Only the count of matching words.
+    Count,
+    /// Only the most frequent letters, all if `None`.
+    Letters(Option<usize>),
+    /// A CSV row with the columns of `ResultFormat::CSV_HEADER`.
+    Csv,
+    /// A custom template, like `{count} words for {input}`.
+    Template(Vec<TemplatePart>),
+}
+
+impl ResultFormat {
+    pub const CSV_HEADER: &'static str =
+        "input,invalid,language,count,words,letters";
+
+    /// Parse a template with `{field}` placeholders, `{{` and `}}` escape
+    /// the braces.
+    pub fn template(template: &str) -> Result<Self, String> {
+        let mut parts: Vec<TemplatePart> = Vec::new();
+        let mut literal = String::new();
+        let mut chars = template.chars();
+
+        while let Some(ch) = chars.next() {
+            match ch {
+                '{' if chars.as_str().starts_with('{') => {
+                    chars.next();
+                    literal.push('{');
+                }
+                '}' if chars.as_str().starts_with('}') => {
+                    chars.next();
+                    literal.push('}');
+                }
+                '{' => {
+                    let (name, rest) = chars
+                        .as_str()
+                        .split_once('}')
+                        .ok_or("unclosed { in template")?;
+                    let field = ResultField::from_string(name)
+                        .ok_or_else(|| format!("unknown field: {name}"))?;
+                    chars = rest.chars();
+
+                    if !literal.is_empty() {
+                        parts.push(TemplatePart::Literal(literal.split_off(0)));
+                    }
+                    parts.push(TemplatePart::Field(field));
+                }
+                '}' => return Err("unmatched } in template".to_owned()),
+                ch => literal.push(ch),
+            }
+        }
+        if !literal.is_empty() {
+            parts.push(TemplatePart::Literal(literal));
+        }
+
+        Ok(Self::Template(parts))
+    }
+
+    /// Parse a format name (`default`, `full`, `count`, `letters`,
+    /// `letters:K`, `csv`), or a template if it contains a `{`.
+    pub fn from_string(string: &str) -> Result<Self, String> {
+        if string.contains('{') {
+            return Self::template(string);
+        }
+
+        match string.split_once(':') {
+            None => match string {
+                "default" => Ok(Self::Default),
+                "full" => Ok(Self::Full),
+                "count" => Ok(Self::Count),
+                "letters" => Ok(Self::Letters(None)),
+                "csv" => Ok(Self::Csv),
+                _ => Err(format!("unknown format: {string}")),
+            },
+            Some(("letters", count)) => count
+                .parse()
+                .map(|count| Self::Letters(Some(count)))
+                .map_err(|_| format!("invalid letter count: {count}")),
+            Some(_) => Err(format!("unknown format: {string}")),
+        }
+    }
+}
+
+/// Formats a `HangmanResult` with a `ResultFormat`.
+pub struct ResultDisplay<'a> {
+    result: &'a HangmanResult,
+    format: &'a ResultFormat,
+}
+
+fn letters(
+    letter_frequency: &[(char, u32)],
+    max_count: Option<usize>,
+) -> impl Display {
+    letter_frequency
+        .iter()
+        .take(max_count.unwrap_or(usize::MAX))
+        .map(|(letter, count)| format!("{letter}: {count}"))
+        .join(", ")
+}
+
+fn write_csv_field(file: &mut fmt::Formatter<'_>, field: &str) -> fmt::Result {
+    if field.contains([',', '"', '\n']) {
+        write!(file, "\"{}\"", field.replace('"', "\"\""))
+    } else {
+        file.write_str(field)
+    }
+}
+
+impl ResultDisplay<'_> {
+    fn write_field(
+        &self,
+        file: &mut fmt::Formatter<'_>,
+        field: ResultField,
+    ) -> fmt::Result {
+        let result = self.result;
+        match field {
+            ResultField::Input => file.write_str(&result.input),
+            ResultField::Invalid => result
+                .invalid
+                .iter()
+                .try_for_each(|ch| file.write_char(*ch)),
+            ResultField::Count => {
+                write!(file, "{}", result.matching_words_count)
+            }
+            ResultField::Words => {
+                write!(file, "{}", result.possible_words.iter().join(", "))
+            }
+            ResultField::Letters => {
+                write!(file, "{}", letters(&result.letter_frequency, None))
+            }
+            ResultField::Language => file
+                .write_str(result.language.as_ref().map_or("", Language::name)),
+            ResultField::NextOffset => match result.next_offset {
+                Some(offset) => write!(file, "{offset}"),
+                None => Ok(()),
+            },
+        }
+    }
+}
+
+impl Display for ResultDisplay<'_> {
+    fn fmt(&self, file: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let result = self.result;
+
+        match self.format {
+            ResultFormat::Default => Display::fmt(result, file),
+            ResultFormat::Full => {
+                write!(
+                    file,
+                    "Found {} words (input: {}, invalid: {})",
+                    result.matching_words_count,
+                    result.input,
+                    result.invalid.iter().collect::<String>(),
+                )?;
+                if result.possible_words.is_empty() {
+                    return Ok(());
+                }
+                write!(
+                    file,
+                    "\n words:   {}",
+                    result.possible_words.iter().join(", ")
+                )?;
+                if !result.letter_frequency.is_empty() {
+                    write!(
+                        file,
+                        "\n letters: {}",
+                        letters(&result.letter_frequency, None)
+                    )?;
+                }
+                Ok(())
+            }
+            ResultFormat::Count => {
+                write!(file, "{}", result.matching_words_count)
+            }
+            ResultFormat::Letters(max_count) => {
+                write!(
+                    file,
+                    "{}",
+                    letters(&result.letter_frequency, *max_count)
+                )
+            }
+            ResultFormat::Csv => {
+                write_csv_field(file, &result.input)?;
+                file.write_char(',')?;
+                write_csv_field(
+                    file,
+                    &result.invalid.iter().collect::<String>(),
+                )?;
+                write!(
+                    file,
+                    ",{},{},",
+                    result.language.as_ref().map_or("", Language::name),
+                    result.matching_words_count
+                )?;
+                write_csv_field(file, &result.possible_words.join(" "))?;
+                file.write_char(',')?;
+                write_csv_field(
+                    file,
+                    &result
+                        .letter_frequency
+                        .iter()
+                        .map(|(letter, count)| format!("{letter}:{count}"))
+                        .join(" "),
+                )
+            }
+            ResultFormat::Template(parts) => {
+                parts.iter().try_for_each(|part| match part {
+                    TemplatePart::Literal(literal) => file.write_str(literal),
+                    TemplatePart::Field(field) => {
+                        self.write_field(file, *field)
+                    }
+                })
+            }
+        }
+    }
+}
+
+impl HangmanResult {
+    /// Display the result in the format.
+    ///
+    /// The width of the formatter is used by `ResultFormat::Default`.
+    #[must_use]
+    pub const fn display_with<'a>(
+        &'a self,
+        format: &'a ResultFormat,
+    ) -> ResultDisplay<'a> {
+        ResultDisplay {
+            result: self,
+            format,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ResultFormat;
+    use crate::language::Language;
+    use crate::solver::{WordSelection, solve_infallible};
+
+    #[test]
+    #[allow(clippy::literal_string_with_formatting_args)]
+    fn test_result_formats() {
+        let result = solve_infallible(
+            "te_t",
+            "n",
+            true,
+            Language::En,
+            Some(2),
+            WordSelection::default(),
+        );
+        let format = |format: &str| {
+            let format = ResultFormat::from_string(format).expect("valid");
+            result.display_with(&format).to_string()
+        };
+
+        assert_eq!(format("default"), result.to_string());
+        assert_eq!(
+            format("full"),
+            "Found 3 words (input: te_t, invalid: n)\n words:   teat, test\n letters: a: 1, s: 1, x: 1"
+        );
+        assert_eq!(format("count"), "3");
+        assert_eq!(format("letters:2"), "a: 1, s: 1");
+        assert_eq!(format("letters"), "a: 1, s: 1, x: 1");
+        assert_eq!(format("csv"), "te_t,n,en,3,teat test,a:1 s:1 x:1");
+        assert_eq!(
+            format("{count} words for {input} ({language}) {{{next_offset}}}"),
+            "3 words for te_t (en) {2}"
+        );
+        assert_eq!(
+            format("{words}|{letters}|{invalid}"),
+            "teat, test|a: 1, s: 1, x: 1|n"
+        );
+
+        for invalid in ["{unknown}", "{count", "count}", "letters:x", "fancy"] {
+            assert!(ResultFormat::from_string(invalid).is_err(), "{invalid}");
+        }
+    }
+}
@@ -0,0 +1,60 @@
+// SPDX-License-Identifier: EUPL-1.2
+
+/// How the words of a result are selected from all matching words.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum WordSelection {
+    /// The first words after skipping `offset` matching words.
+    First { offset: usize },
+    /// A random sample of the matching words, the same seed always selects
+    /// the same words.
+    Sample { seed: u64 },
+}
+
+impl Default for WordSelection {
+    #[inline]
+    fn default() -> Self {
+        Self::First { offset: 0 }
+    }
+}
+
+impl WordSelection {
+    /// Sample the words if a seed is given, otherwise take the first words.
+    #[inline]
+    #[must_use]
+    pub const fn new(offset: usize, seed: Option<u64>) -> Self {
+        match seed {
+            Some(seed) => Self::Sample { seed },
+            None => Self::First { offset },
+        }
+    }
+
+    /// Like `new`, but an offset together with a seed is a `ValueError`.
+    #[cfg(feature = "pyo3")]
+    pub fn from_py_args(
+        offset: usize,
+        seed: Option<u64>,
+    ) -> pyo3::PyResult<Self> {
+        if seed.is_some() && offset != 0 {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "offset can't be used with seed",
+            ));
+        }
+
+        Ok(Self::new(offset, seed))
+    }
+
+    /// Like `new`, but an offset together with a seed is an error.
+    #[cfg(feature = "wasm-bindgen")]
+    pub fn from_js_args(
+        offset: usize,
+        seed: Option<u64>,
+    ) -> Result<Self, wasm_bindgen::JsValue> {
+        if seed.is_some() && offset != 0 {
+            return Err(wasm_bindgen::JsValue::from_str(
+                "offset can't be used with seed",
+            ));
+        }
+
+        Ok(Self::new(offset, seed))
+    }
+}
@@ -1,10 +1,14 @@
 #!/usr/bin/env python3
 
+import asyncio
+import pickle
+import subprocess
+import sys
 from itertools import permutations
 from concurrent.futures import wait
 from collections.abc import Sequence
 
-from hangman_solver import Language, read_words_with_length
+from hangman_solver import GameSession, HangmanResult, Language, Pattern, WordList, matches_iter, read_words_with_length, solve, solve_async, solve_crossword, solve_many
 
 german_words = read_words_with_length(Language.DeUmlauts, 4)
 english_words = read_words_with_length(Language.En, 4)
@@ -95,3 +99,146 @@ for words in sequences:
 
 
 assert counter == 96, f"{counter} != 96"
+
+
+
+session = GameSession(Language.En, "____")
+session.guess("e", [1])
+session.guess("x")
+assert session.language == Language.En
+assert session.pattern == "_e__"
+assert session.invalid_letters == ["x"]
+assert session.guesses == [("e", [1]), ("x", [])]
+assert not session.is_solved
+assert session.result(10) == solve("_e__", "x", Language.En, 10)
+
+restored_session = pickle.loads(pickle.dumps(session))
+assert restored_session.pattern == session.pattern
+assert restored_session.guesses == session.guesses
+assert restored_session.result(10) == session.result(10)
+
+assert session.undo()
+assert session.guesses == [("e", [1])]
+assert session.undo()
+assert not session.undo()
+assert session.pattern == "____"
+
+solved_session = GameSession(Language.En, "t__t")
+solved_session.guess("e", [1])
+solved_session.guess("s", [2])
+assert solved_session.is_solved
+assert solved_session.pattern == "test"
+assert solved_session.suggestion() is None
+try:
+    solved_session.guess("e")
+except ValueError:
+    pass
+else:
+    raise AssertionError("guessing a letter twice worked")
+
+
+
+pattern = Pattern("t__t", "e")
+assert pattern.pattern == "t__t"
+assert pattern.invalid_letters == ["e"]
+assert pattern.known_letters_count == 2
+assert not pattern.crossword
+assert pattern.matches("tilt")
+assert not pattern.matches("test")
+assert pattern.solve(Language.En, 5) == solve("t__t", "e", Language.En, 5)
+assert pattern.solve(Language.En, 2, offset=2) == solve("t__t", "e", Language.En, 2, 2)
+assert list(pattern.matches_iter(Language.En)) == list(matches_iter("t__t", "e", Language.En))
+
+crossword_pattern = Pattern("t__t", crossword=True)
+assert crossword_pattern.crossword
+assert crossword_pattern.solve(Language.En, 5) == solve_crossword("t__t", "", Language.En, 5)
+
+assert Pattern("t??t", wildcards="?").pattern == "t__t"
+
+
+
+word_list = WordList(["test", "text", "tent", "Tilt", "toast", "a"])
+assert len(word_list) == 6
+assert word_list.max_word_length == 5
+assert list(word_list.read_words(4)) == ["tent", "test", "text", "tilt"]
+assert list(matches_iter("t__t", "e", word_list)) == ["tilt"]
+assert Pattern("t__t").solve(word_list, 10).words == ["tent", "test", "text", "tilt"]
+
+word_list_result = solve("te_t", "", word_list, 10)
+assert word_list_result.language is None
+assert word_list_result.matching_words_count == 3
+assert word_list_result == solve("te_t", "", word_list, 10)
+custom_words = word_list.read_words(5)
+del word_list
+# the results and the words outlive the word list
+assert word_list_result.words == ["tent", "test", "text"]
+assert list(custom_words) == ["toast"]
+
+
+
+many_patterns = ["t__t", ("_____", "aeiou"), Pattern("te_t"), "__", ("_e__", ["a", "s"])] * 20
+many_results = solve_many(many_patterns, Language.En, 5)
+assert len(many_results) == len(many_patterns)
+for many_pattern, many_result in zip(many_patterns, many_results):
+    if isinstance(many_pattern, Pattern):
+        assert many_result == many_pattern.solve(Language.En, 5)
+    elif isinstance(many_pattern, tuple):
+        assert many_result == solve(*many_pattern, Language.En, 5)
+    else:
+        assert many_result == solve(many_pattern, "", Language.En, 5)
+
+
+
+for language in Language.values():
+    assert pickle.loads(pickle.dumps(language)) == language
+
+english_result = solve("te_t", "", Language.En, 3)
+for result in (english_result, solve("te_t", "", WordList(["test", "text"]), 3)):
+    assert pickle.loads(pickle.dumps(result)) == result
+    assert HangmanResult.from_dict(result.to_dict()) == result
+    assert hash(HangmanResult.from_dict(result.to_dict())) == hash(result)
+assert english_result.to_dict()["language"] == "en"
+assert english_result.to_dict()["words"] == ["teat", "tent", "test"]
+
+for words in (english_words, english_words[10:20], english_words[:0]):
+    pickled_words = pickle.loads(pickle.dumps(words))
+    assert type(pickled_words) is type(words)
+    assert pickled_words == words
+    assert list(pickled_words) == list(words)
+try:
+    pickle.dumps(WordList(["test"]).read_words(4))
+except TypeError:
+    pass
+else:
+    raise AssertionError("the words of a custom word list were pickled")
+
+
+async def solve_in_background() -> None:
+    result = await solve_async("te_t", "", Language.En, 10)
+    assert result == solve("te_t", "", Language.En, 10)
+
+    future = solve_async("_" * 8, "", Language.En, 10)
+    future.cancel()
+    try:
+        await future
+    except asyncio.CancelledError:
+        pass
+    else:
+        raise AssertionError("the cancelled solve finished")
+
+
+asyncio.run(solve_in_background())
+
+# the interpreter has to exit cleanly after awaiting solves in the background
+exit_code = subprocess.run(
+    [
+        sys.executable,
+        "-c",
+        "import asyncio, hangman_solver as h\n"
+        "async def main():\n"
+        "    h.solve_async('_' * 8, '', h.Language.En, 10)\n"
+        "    await h.solve_async('te_t', '', h.Language.En, 10)\n"
+        "asyncio.run(main())\n",
+    ],
+).returncode
+assert exit_code == 0, f"{exit_code} != 0"
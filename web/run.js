@@ -1,14 +1,14 @@
 #!/bin/env -S deno run --allow-net=github.asozial.org,asozial.org,deno.land --allow-read=./pkg/
 import { parse } from "https://deno.land/std@0.207.0/flags/mod.ts";
 import { exit } from "https://deno.land/x/exit/mod.ts";
-import init, { solve_hangman } from "../pkg/hangman_solver_lib.js";
+import init, { WordList } from "../pkg/hangman_solver_lib.js";
 
 
 const flags = parse(Deno.args, {
     boolean: ["crossword"],
     string: ["input", "invalid", "language"],
-    number: ["maxwords"],
-    default: { crossword: false, maxwords: 10, language: "de_umlauts" },
+    number: ["maxwords", "offset", "seed"],
+    default: { crossword: false, maxwords: 10, offset: 0, language: "de_umlauts" },
 });
 
 const response = await fetch(
@@ -18,17 +18,25 @@ if (!response.ok) {
     console.error(`${response.url} returned ${response.status} ${response.statusText}`);
     exit(1);
 }
-const words = (await response.text()).split("\n");
+const text = await response.text();
 
 await init();
 
-const result = solve_hangman(
-    words,
+const words = WordList.fromText(text);
+const solver = words.solveChunked(
     flags.input || "",
     flags.invalid || "",
     flags.maxwords ?? 10,
+    flags.offset ?? 0,
     flags.crossword ?? false,
+    flags.seed === undefined ? undefined : BigInt(flags.seed),
 );
+while (!solver.step(10000)) {
+    console.error(`${Math.round(solver.progress * 100)}% of ${solver.total} words`);
+    await new Promise((resolve) => setTimeout(resolve));
+}
+const result = solver.result();
+solver.free();
 
 console.log({
     input: result.input,
@@ -37,8 +45,14 @@ console.log({
 });
 
 if (result.matching_words_count) {
-    console.log(`Letter frequency: ${result.letter_frequency}`);
+    const letterFrequency = result.letter_frequency
+        .map(({ letter, count }) => `${letter}: ${count}`)
+        .join(", ");
+    console.log(`Letter frequency: ${letterFrequency}`);
     console.log(`Words (${result.possible_words.length}/${result.matching_words_count}): ${result.possible_words.join(", ")}`);
+    if (result.next_offset !== undefined) {
+        console.log(`More words with --offset ${result.next_offset}`);
+    }
 } else {
     console.log("Nothing found");
 }